@@ -0,0 +1,350 @@
+//! Runs the criterion benchmarks and keeps a history of the results per git commit,
+//! so that regressions can be spotted between commits.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fs::{self, create_dir_all},
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+use utils::ansi::*;
+
+use crate::config::Config;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Run {
+    pub commit: String,
+    pub timestamp: String,
+    /// mean time in nanoseconds, keyed by the criterion benchmark id
+    pub results: BTreeMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+/// The criterion filter (a regex on the benchmark id) selecting a year and/or a day
+pub fn filter(year: Option<u16>, day: Option<u8>) -> String {
+    match (year, day) {
+        (Some(year), Some(day)) => format!("^{year}/day{day:02}/"),
+        (Some(year), None) => format!("^{year}/"),
+        (None, Some(day)) => format!("/day{day:02}/"),
+        (None, None) => String::new(),
+    }
+}
+
+pub fn run(year: Option<u16>, day: Option<u8>, threshold: f64) -> Result<(), Box<dyn Error>> {
+    let config = Config::default();
+    let started = SystemTime::now();
+
+    let status = Command::new("cargo")
+        .args(["bench", "-p", "aoc-solutions", "--bench", "benchmark", "--"])
+        .arg(filter(year, day))
+        .status()?;
+
+    if !status.success() {
+        return Err(format!("cargo bench failed with {status}").into());
+    }
+
+    let results = collect(&config.criterion_dir, started)?;
+    if results.is_empty() {
+        return Err("No benchmarks matched the given year and day".into());
+    }
+
+    let commit = commit()?;
+    let mut history = History::read(&config.bench_history)?;
+    // every benchmark is compared to its own latest result, as runs may cover different days
+    let baselines = results
+        .keys()
+        .filter_map(|id| {
+            let (run, mean) = history.baseline(&commit, id)?;
+            Some((id.clone(), (run.commit.clone(), mean)))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    history.record(Run {
+        commit: commit.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        results: results.clone(),
+    });
+    history.write(&config.bench_history)?;
+
+    let regressions = print_comparison(&results, &baselines, threshold);
+
+    match regressions {
+        0 => Ok(()),
+        n => Err(format!("{n} benchmark(s) regressed by more than {threshold}%").into()),
+    }
+}
+
+/// Reads the mean of every benchmark criterion has written to since `since`
+fn collect(
+    criterion_dir: &Path,
+    since: SystemTime,
+) -> Result<BTreeMap<String, f64>, Box<dyn Error>> {
+    let mut results = BTreeMap::new();
+
+    for dir in estimate_dirs(criterion_dir)? {
+        let estimates = dir.join("estimates.json");
+
+        if fs::metadata(&estimates)?.modified()? < since {
+            continue; // stale result from a previous run
+        }
+
+        let id: BenchmarkId =
+            serde_json::from_str(&fs::read_to_string(dir.join("benchmark.json"))?)?;
+        let estimates: Estimates = serde_json::from_str(&fs::read_to_string(estimates)?)?;
+
+        results.insert(id.full_id, estimates.mean.point_estimate);
+    }
+    Ok(results)
+}
+
+/// Finds every `new` directory criterion stores the latest estimates in
+fn estimate_dirs(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut dirs = vec![];
+
+    if !dir.is_dir() {
+        return Ok(dirs);
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if !path.is_dir() {
+            continue;
+        }
+
+        if path.ends_with("new") && path.join("estimates.json").exists() {
+            dirs.push(path);
+        } else {
+            dirs.extend(estimate_dirs(&path)?);
+        }
+    }
+    Ok(dirs)
+}
+
+/// The short hash of the current commit, suffixed with `-dirty` when there are uncommitted changes
+fn commit() -> Result<String, Box<dyn Error>> {
+    let hash = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()?;
+
+    if !hash.status.success() {
+        return Err("Failed to resolve the current git commit".into());
+    }
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()?;
+    let hash = String::from_utf8(hash.stdout)?.trim().to_string();
+
+    match status.stdout.is_empty() {
+        true => Ok(hash),
+        false => Ok(format!("{hash}-dirty")),
+    }
+}
+
+impl History {
+    pub fn read(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse benchmark history {path:?}: {e}").into())
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// The most recent run of the benchmark recorded for a different commit, with its mean
+    pub fn baseline(&self, commit: &str, id: &str) -> Option<(&Run, f64)> {
+        self.runs
+            .iter()
+            .rev()
+            .filter(|run| run.commit != commit)
+            .find_map(|run| Some((run, *run.results.get(id)?)))
+    }
+
+    /// Stores a run, merging it into the existing entry when the commit was benchmarked before
+    pub fn record(&mut self, run: Run) {
+        match self.runs.iter().position(|r| r.commit == run.commit) {
+            Some(index) => {
+                let mut existing = self.runs.remove(index);
+                existing.timestamp = run.timestamp;
+                existing.results.extend(run.results);
+                self.runs.push(existing);
+            }
+            None => self.runs.push(run),
+        }
+    }
+}
+
+/// The relative change from `baseline` to `current`, in percent
+pub fn change(baseline: f64, current: f64) -> f64 {
+    (current - baseline) / baseline * 100.0
+}
+
+/// Prints a table comparing the results to their baselines, given as commit and mean per
+/// benchmark id, and returns the number of regressions
+fn print_comparison(
+    results: &BTreeMap<String, f64>,
+    baselines: &BTreeMap<String, (String, f64)>,
+    threshold: f64,
+) -> usize {
+    let width = results.keys().map(|id| id.len()).max().unwrap_or(0);
+    let mut regressions = 0;
+
+    println!();
+    match baselines.is_empty() {
+        false => println!(
+            "✨ {BOLD}{YELLOW}Compared to the latest other commit with each benchmark{RESET} ✨"
+        ),
+        true => println!("✨ {BOLD}{YELLOW}No baseline recorded yet{RESET} ✨"),
+    }
+    println!();
+    println!(
+        "{:width$}  {:>12}  {:>12}  {:>12}  {:>9}",
+        "Benchmark", "Commit", "Baseline", "Current", "Change"
+    );
+
+    for (id, &current) in results {
+        let (commit, previous_str, change_str) = match baselines.get(id) {
+            Some((commit, previous)) => {
+                let previous = *previous;
+                let change = change(previous, current);
+                let colored = if change > threshold {
+                    regressions += 1;
+                    format!("{RED}{change:>+8.1}%{RESET} ⚠")
+                } else if change < -threshold {
+                    format!("{GREEN}{change:>+8.1}%{RESET}")
+                } else {
+                    format!("{change:>+8.1}%")
+                };
+                (commit.as_str(), nanos(previous), colored)
+            }
+            None => ("-", "-".to_string(), format!("{:>9}", "new")),
+        };
+
+        println!(
+            "{id:width$}  {commit:>12}  {previous_str:>12}  {YELLOW}{:>12}{RESET}  {change_str}",
+            nanos(current)
+        );
+    }
+    regressions
+}
+
+/// Formats a duration in nanoseconds using the most readable unit
+fn nanos(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{n:.1} ns"),
+        n if n < 1e6 => format!("{:.1} μs", n / 1e3),
+        n if n < 1e9 => format!("{:.1} ms", n / 1e6),
+        n => format!("{:.2} s", n / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use crate::bench::{change, filter, nanos, History, Run};
+
+    fn run(commit: &str, results: &[(&str, f64)]) -> Run {
+        Run {
+            commit: commit.to_string(),
+            timestamp: String::new(),
+            results: results
+                .iter()
+                .map(|&(id, mean)| (id.to_string(), mean))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn filter_test() {
+        assert_eq!("^2023/day07/", filter(Some(2023), Some(7)));
+        assert_eq!("^2023/", filter(Some(2023), None));
+        assert_eq!("/day17/", filter(None, Some(17)));
+        assert_eq!("", filter(None, None));
+    }
+
+    #[test]
+    fn record_test() {
+        let mut history = History::default();
+        history.record(run("a", &[("x", 1.0)]));
+        history.record(run("b", &[("x", 2.0)]));
+        history.record(run("a", &[("y", 3.0)]));
+
+        assert_eq!(2, history.runs.len());
+        assert_eq!(run("a", &[("x", 1.0), ("y", 3.0)]), history.runs[1]);
+    }
+
+    #[test]
+    fn baseline_test() {
+        let mut history = History::default();
+        assert_eq!(None, history.baseline("a", "x"));
+
+        history.record(run("a", &[("x", 1.0)]));
+        history.record(run("b", &[("x", 2.0)]));
+
+        assert_eq!("a", history.baseline("b", "x").unwrap().0.commit);
+        assert_eq!("b", history.baseline("c", "x").unwrap().0.commit);
+        assert_eq!(2.0, history.baseline("c", "x").unwrap().1);
+    }
+
+    #[test]
+    fn baseline_per_benchmark_test() {
+        let mut history = History::default();
+        history.record(run("a", &[("day03", 1.0)]));
+        history.record(run("b", &[("day17", 2.0)]));
+
+        // b did not run day03, so it is compared to a
+        let (run, mean) = history.baseline("c", "day03").unwrap();
+        assert_eq!(("a", 1.0), (run.commit.as_str(), mean));
+        assert_eq!("b", history.baseline("c", "day17").unwrap().0.commit);
+        assert_eq!(None, history.baseline("c", "day21"));
+        assert_eq!(None, history.baseline("a", "day03"));
+    }
+
+    #[test]
+    fn change_test() {
+        assert_eq!(10.0, change(100.0, 110.0));
+        assert_eq!(-50.0, change(100.0, 50.0));
+    }
+
+    #[test]
+    fn nanos_test() {
+        assert_eq!("12.0 ns", nanos(12.0));
+        assert_eq!("1.5 μs", nanos(1_500.0));
+        assert_eq!("2.0 ms", nanos(2_000_000.0));
+        assert_eq!("3.00 s", nanos(3e9));
+    }
+}
@@ -1,9 +1,12 @@
-use std::io::{stdin, stdout, Write};
+use std::{
+    io::{stdin, stdout, Write},
+    path::PathBuf,
+};
 
 use chrono::Datelike;
 use clap::Parser;
 
-use crate::template::Template;
+use crate::{leaderboard::DEFAULT_ENDPOINT, template::Template};
 
 const FIRST_AOC_YEAR: i64 = 2015;
 
@@ -44,4 +47,37 @@ pub enum AocCli {
         #[arg(short, long, default_value = "solution")]
         template: Template,
     },
+    /// Show the standings of a private leaderboard
+    Leaderboard {
+        /// the id of the private leaderboard to fetch (the number in the leaderboard url)
+        #[arg(short, long, required_unless_present = "file", conflicts_with = "file")]
+        id: Option<u64>,
+        /// read the leaderboard from a json file instead of fetching it
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+        /// the year of the event to fetch (or default this year)
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_AOC_YEAR..=chrono::Utc::now().year() as i64), default_value_t = chrono::Utc::now().year() as u16)]
+        year: u16,
+        /// show the star timestamps for a single day instead of the overview
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// the server to fetch the leaderboard from. Override it to test against a local server
+        #[arg(long, env = "AOC_ENDPOINT", default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+        /// the session cookie used to authenticate against the server
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+    },
+    /// Run the benchmarks and compare them to the last recorded baseline
+    Bench {
+        /// only benchmark solutions from this year
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_AOC_YEAR..=chrono::Utc::now().year() as i64))]
+        year: Option<u16>,
+        /// only benchmark solutions for this day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// the slowdown (in percent) compared to the baseline before a benchmark is flagged as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
 }
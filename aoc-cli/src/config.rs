@@ -1,15 +1,19 @@
 use std::path::PathBuf;
 
+use utils::parser::input_path;
+
 pub struct Config {
     pub solution_dir: PathBuf,
-    pub input_dir: PathBuf,
+    pub criterion_dir: PathBuf,
+    pub bench_history: PathBuf,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
             solution_dir: PathBuf::from("aoc-solutions/src"),
-            input_dir: PathBuf::from("resources/input"),
+            criterion_dir: PathBuf::from("target/criterion"),
+            bench_history: PathBuf::from("resources/benchmarks/history.json"),
         }
     }
 }
@@ -27,7 +31,7 @@ impl PathConfig {
             solution_file: config
                 .solution_dir
                 .join(format!("year{year}/day{day:02}.rs")),
-            input_file: config.input_dir.join(format!("year{year}/day{day:02}.txt")),
+            input_file: input_path(year, day),
         }
     }
 }
@@ -0,0 +1,334 @@
+//! Private leaderboards, as served by `/{year}/leaderboard/private/view/{id}.json`
+
+use std::{collections::HashMap, error::Error, fmt::Write, path::Path};
+
+use chrono::{TimeZone, Utc};
+use serde::Deserialize;
+use utils::{ansi::*, parser};
+
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+
+#[derive(Deserialize, Debug)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    /// the score as computed by the server, which skips days that were excluded from scoring
+    pub local_score: u64,
+    /// completed days, mapping day -> part -> star
+    #[serde(default)]
+    pub completion_day_level: HashMap<u8, HashMap<u8, Star>>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Star {
+    pub get_star_ts: i64,
+    pub star_index: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star(&self, day: u8, part: u8) -> Option<Star> {
+        self.completion_day_level
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+            .copied()
+    }
+}
+
+/// Fetches the raw leaderboard json. The session cookie is required by adventofcode.com,
+/// but can be omitted when testing against a local server
+pub fn fetch(
+    endpoint: &str,
+    year: u16,
+    id: u64,
+    session: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let url = format!(
+        "{}/{year}/leaderboard/private/view/{id}.json",
+        endpoint.trim_end_matches('/')
+    );
+
+    let mut request = ureq::get(&url);
+    if let Some(session) = session {
+        request = request.set("Cookie", &format!("session={session}"));
+    }
+
+    let response = request
+        .call()
+        .map_err(|e| format!("Failed to fetch leaderboard from {url}: {e}"))?;
+
+    Ok(response.into_string()?)
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, Box<dyn Error>> {
+        serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse leaderboard json: {e}").into())
+    }
+
+    pub fn read(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let json = parser::read(path).map_err(|e| e.to_string())?;
+        Self::parse(&json).map_err(|e| format!("{e} in {path:?}").into())
+    }
+
+    /// Fetches and parses the leaderboard, see [`fetch`]
+    pub fn download(
+        endpoint: &str,
+        year: u16,
+        id: u64,
+        session: Option<&str>,
+    ) -> Result<Self, Box<dyn Error>> {
+        // adventofcode.com answers with a login page instead of json without a valid session
+        Self::parse(&fetch(endpoint, year, id, session)?)
+            .map_err(|e| format!("{e}. Is the session cookie valid?").into())
+    }
+
+    /// Recomputes the local score of every member. For every star, the first member to get it
+    /// receives one point per member of the leaderboard, the second one point less, and so on.
+    pub fn local_scores(&self) -> HashMap<u64, u64> {
+        let member_count = self.members.len() as u64;
+        let mut scores: HashMap<u64, u64> = self.members.values().map(|m| (m.id, 0)).collect();
+
+        for day in 1..=25 {
+            for part in 1..=2 {
+                let mut finishers = self
+                    .members
+                    .values()
+                    .filter_map(|m| m.star(day, part).map(|star| (star, m.id)))
+                    .collect::<Vec<_>>();
+
+                finishers.sort_by_key(|(star, _)| (star.get_star_ts, star.star_index));
+
+                for (rank, (_, id)) in finishers.iter().enumerate() {
+                    *scores.entry(*id).or_default() += member_count - rank as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    /// Members ordered by their recomputed local score, paired with that score
+    pub fn rankings(&self) -> Vec<(&Member, u64)> {
+        let scores = self.local_scores();
+
+        let mut rankings = self
+            .members
+            .values()
+            .map(|m| (m, scores[&m.id]))
+            .collect::<Vec<_>>();
+
+        rankings.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .cmp(a_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+        rankings
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let days = 1..=25u8;
+
+        let _ = writeln!(
+            out,
+            "✨ {BOLD}{YELLOW}{} Private Leaderboard{RESET} ✨ ({} members)\n",
+            self.event,
+            self.members.len()
+        );
+
+        // day numbers are printed vertically, as on the website
+        let tens = days.clone().map(|d| match d / 10 {
+            0 => ' ',
+            n => char::from(b'0' + n),
+        });
+        let ones = days.clone().map(|d| char::from(b'0' + d % 10));
+        let _ = writeln!(out, "{:15}{}", "", tens.collect::<String>());
+        let _ = writeln!(out, "{:15}{}", "", ones.collect::<String>());
+
+        for (rank, (member, score)) in self.rankings().iter().enumerate() {
+            let stars = days
+                .clone()
+                .map(|day| match (member.star(day, 1), member.star(day, 2)) {
+                    (Some(_), Some(_)) => format!("{YELLOW}*{RESET}"),
+                    (Some(_), None) => format!("{BLUE}*{RESET}"),
+                    _ => format!("{WHITE}.{RESET}"),
+                })
+                .collect::<String>();
+
+            let server_score = match member.local_score {
+                s if s != *score => format!(" {RED}(server: {s}){RESET}"),
+                _ => String::new(),
+            };
+
+            let _ = writeln!(
+                out,
+                "{:>3}) {BOLD}{score:>5}{RESET} {:>3} {stars}  {}{server_score}",
+                rank + 1,
+                member.stars,
+                member.display_name()
+            );
+        }
+        out
+    }
+
+    /// Renders the time each member needed for both parts of a day, counted from the
+    /// moment the puzzle unlocked, and the time spent between part one and part two
+    pub fn render_day(&self, day: u8) -> Result<String, Box<dyn Error>> {
+        let year = self.event.parse::<i32>()?;
+
+        // puzzles unlock at midnight EST (UTC-5)
+        let unlock = Utc
+            .with_ymd_and_hms(year, 12, day as u32, 5, 0, 0)
+            .single()
+            .ok_or(format!("Invalid puzzle date {year}-12-{day}"))?
+            .timestamp();
+
+        let mut finishers = self
+            .members
+            .values()
+            .filter_map(|m| m.star(day, 1).map(|one| (m, one, m.star(day, 2))))
+            .collect::<Vec<_>>();
+
+        // members with both stars first, then by their latest star
+        finishers.sort_by_key(|(_, one, two)| match two {
+            Some(two) => (0, two.get_star_ts, two.star_index),
+            None => (1, one.get_star_ts, one.star_index),
+        });
+
+        let mut out = String::new();
+        let _ = writeln!(out, "✨ {BOLD}{YELLOW}{year} Day {day:02}{RESET} ✨\n");
+        let _ = writeln!(
+            out,
+            "{:>4} {:>10} {:>10} {:>10}  Name",
+            "", "Part 1", "Part 2", "Delta"
+        );
+
+        for (rank, (member, one, two)) in finishers.iter().enumerate() {
+            let part_one = duration(one.get_star_ts - unlock);
+            let (part_two, delta) = match two {
+                Some(two) => (
+                    duration(two.get_star_ts - unlock),
+                    duration(two.get_star_ts - one.get_star_ts),
+                ),
+                None => ("-".to_string(), "-".to_string()),
+            };
+
+            let _ = writeln!(
+                out,
+                "{:>3}) {YELLOW}{part_one:>10}{RESET} {YELLOW}{part_two:>10}{RESET} {GREEN}{delta:>10}{RESET}  {}",
+                rank + 1,
+                member.display_name()
+            );
+        }
+
+        Ok(out)
+    }
+}
+
+/// Formats a number of seconds as `hh:mm:ss`
+fn duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::leaderboard::{duration, Leaderboard};
+
+    // 2023-12-01T05:00:00Z is 1701406800
+    const EXAMPLE: &str = r#"{
+  "event": "2023",
+  "owner_id": 1,
+  "members": {
+    "1": {
+      "id": 1, "name": "alice", "stars": 3, "local_score": 8, "global_score": 0, "last_star_ts": 1701493500,
+      "completion_day_level": {
+        "1": { "1": { "get_star_ts": 1701407100, "star_index": 10 }, "2": { "get_star_ts": 1701407400, "star_index": 20 } },
+        "2": { "1": { "get_star_ts": 1701493500, "star_index": 50 } }
+      }
+    },
+    "2": {
+      "id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0, "last_star_ts": 1701407300,
+      "completion_day_level": {
+        "1": { "1": { "get_star_ts": 1701407000, "star_index": 5 }, "2": { "get_star_ts": 1701407500, "star_index": 25 } }
+      }
+    },
+    "3": {
+      "id": 3, "name": "carol", "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+      "completion_day_level": {}
+    }
+  }
+}"#;
+
+    #[test]
+    fn parse_test() {
+        let leaderboard = Leaderboard::parse(EXAMPLE).unwrap();
+        assert_eq!("2023", leaderboard.event);
+        assert_eq!(3, leaderboard.members.len());
+        assert_eq!(
+            1701407400,
+            leaderboard.members["1"].star(1, 2).unwrap().get_star_ts
+        );
+        assert!(leaderboard.members["1"].star(2, 2).is_none());
+        assert_eq!(
+            "(anonymous user #2)",
+            leaderboard.members["2"].display_name()
+        );
+    }
+
+    #[test]
+    fn local_scores_test() {
+        let leaderboard = Leaderboard::parse(EXAMPLE).unwrap();
+        let scores = leaderboard.local_scores();
+
+        for member in leaderboard.members.values() {
+            assert_eq!(member.local_score, scores[&member.id]);
+        }
+    }
+
+    #[test]
+    fn rankings_test() {
+        let leaderboard = Leaderboard::parse(EXAMPLE).unwrap();
+        let ids = leaderboard
+            .rankings()
+            .iter()
+            .map(|(m, _)| m.id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], ids);
+    }
+
+    #[test]
+    fn render_day_test() {
+        let leaderboard = Leaderboard::parse(EXAMPLE).unwrap();
+        let rendered = leaderboard.render_day(1).unwrap();
+
+        assert!(rendered.contains("00:05:00"));
+        assert!(rendered.contains("00:10:00"));
+        assert!(!rendered.contains("carol"));
+    }
+
+    #[test]
+    fn duration_test() {
+        assert_eq!("00:00:00", duration(0));
+        assert_eq!("01:01:01", duration(3661));
+        assert_eq!("26:00:00", duration(26 * 3600));
+    }
+}
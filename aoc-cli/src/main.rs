@@ -4,11 +4,13 @@ use clap::Parser;
 use config::PathConfig;
 use file::PathBufExt;
 
-use crate::{cli::AocCli, template::TemplateData};
+use crate::{cli::AocCli, leaderboard::Leaderboard, template::TemplateData};
 
+mod bench;
 mod cli;
 mod config;
 mod file;
+mod leaderboard;
 mod template;
 
 fn run_setup(paths: PathConfig, solution_template: Option<&str>) -> Result<(), Box<dyn Error>> {
@@ -40,6 +42,30 @@ fn main() -> Result<(), Box<dyn Error>> {
             });
             run_setup(PathConfig::from(year, day), Some(&template_output))?;
         }
+        AocCli::Leaderboard {
+            id,
+            file,
+            year,
+            day,
+            endpoint,
+            session,
+        } => {
+            let leaderboard = match (file, id) {
+                (Some(file), _) => Leaderboard::read(&file)?,
+                (None, Some(id)) => Leaderboard::download(&endpoint, year, id, session.as_deref())?,
+                (None, None) => unreachable!("clap requires either an id or a file"),
+            };
+
+            match day {
+                Some(day) => print!("{}", leaderboard.render_day(day)?),
+                None => print!("{}", leaderboard.render()),
+            }
+        }
+        AocCli::Bench {
+            year,
+            day,
+            threshold,
+        } => bench::run(year, day, threshold)?,
     }
     Ok(())
 }
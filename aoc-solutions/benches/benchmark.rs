@@ -1,5 +1,6 @@
-use std::iter::empty;
+use std::hint::black_box;
 
+use aoc_solutions::registry;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 #[derive(Debug, Clone)]
@@ -7,8 +8,15 @@ struct Benchmark {
     benchmark: fn(&mut Criterion),
 }
 
+/// Registers a criterion group per day, e.g. `2023/day17`, so single days can be selected
+/// with `cargo bench -- 2023/day17`. The parts are timed on a pre-parsed input, as they only
+/// borrow it and the parsing is benchmarked on its own. Variants are benchmarked next to the
+/// default implementation, e.g. `2023/day17/part one/buckets`.
 macro_rules! benchmark {
     ($year:tt, $day:tt) => {
+        benchmark!($year, $day, part_one: [], part_two: [])
+    };
+    ($year:tt, $day:tt, part_one: [$($one:ident => $one_fn:ident),*], part_two: [$($two:ident => $two_fn:ident),*]) => {
         Benchmark {
             benchmark: |c: &mut $crate::Criterion| {
                 use aoc_solutions::$year::$day::*;
@@ -21,43 +29,32 @@ macro_rules! benchmark {
                     ".txt"
                 ]);
 
-                let mut group = c.benchmark_group(stringify!($year));
+                let year = stringify!($year).trim_start_matches("year");
+                let mut group = c.benchmark_group(format!("{}/{}", year, stringify!($day)));
+
+                group.bench_function("parse", |b| b.iter(|| input(black_box(raw_input))));
+
+                let parsed = input(raw_input);
+                group.bench_function("part one", |b| b.iter(|| part_one(black_box(&parsed))));
+                $(group.bench_function(concat!("part one/", stringify!($one)), |b| {
+                    b.iter(|| $one_fn(black_box(&parsed)))
+                });)*
+
+                group.bench_function("part two", |b| b.iter(|| part_two(black_box(&parsed))));
+                $(group.bench_function(concat!("part two/", stringify!($two)), |b| {
+                    b.iter(|| $two_fn(black_box(&parsed)))
+                });)*
 
-                group.bench_function(concat!(stringify!($day), " parser"), |b| {
-                    b.iter(|| input(raw_input))
-                });
-                group.bench_function(concat!(stringify!($day), " part one"), |b| {
-                    b.iter(|| {
-                        let input = input(raw_input);
-                        part_one(&input);
-                    })
-                });
-                group.bench_function(concat!(stringify!($day), " part two"), |b| {
-                    b.iter(|| {
-                        let input = input(raw_input);
-                        part_two(&input);
-                    })
-                });
+                group.finish();
             },
         }
     };
 }
 
-fn benchmarks_2023() -> Vec<Benchmark> {
-    [
-        benchmark!(year2023, day01),
-        benchmark!(year2023, day02),
-        benchmark!(year2023, day03),
-        benchmark!(year2023, day17),
-        benchmark!(year2023, day18),
-        benchmark!(year2023, day19),
-        benchmark!(year2023, day20),
-    ]
-    .to_vec()
-}
-
 fn benchmark_wrapper(c: &mut Criterion) {
-    empty().chain(benchmarks_2023()).for_each(|b| {
+    let benchmarks: Vec<Benchmark> = registry!(benchmark);
+
+    benchmarks.iter().for_each(|b| {
         (b.benchmark)(c);
     });
 }
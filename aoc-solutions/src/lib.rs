@@ -12,3 +12,26 @@ pub mod year2023 {
     pub mod day21;
     pub mod day22;
 }
+
+/// Registry of all solutions, shared by the runner and the benchmarks. Expands to a `vec!`
+/// of `$macro!(year, day)` for every registered day, e.g. `registry!(solution)`. Days with
+/// alternative implementations list them per part as `name => function`.
+#[macro_export]
+macro_rules! registry {
+    ($macro:ident) => {
+        vec![
+            $macro!(year2023, day01),
+            $macro!(year2023, day02),
+            $macro!(year2023, day03),
+            $macro!(year2023, day17,
+                part_one: [buckets => part_one_buckets],
+                part_two: [buckets => part_two_buckets]
+            ),
+            $macro!(year2023, day18),
+            $macro!(year2023, day19),
+            $macro!(year2023, day20),
+            $macro!(year2023, day21),
+            $macro!(year2023, day22),
+        ]
+    };
+}
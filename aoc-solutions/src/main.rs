@@ -1,64 +1,284 @@
 use aoc_solutions::{
-    setup::{FilterExt, Solution},
+    registry,
+    setup::{Answer, FilterExt, Measurement, Report, Solution, DEFAULT_VARIANT},
     solution, year2023,
 };
-use std::{env::args, iter::empty, time::Instant};
-use utils::ansi::*;
+use serde_json::json;
+use std::{env::args, iter::empty, process::exit, time::Duration};
+use utils::{alloc::CountingAllocator, ansi::*};
+
+const FIRST_AOC_YEAR: u16 = 2015;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Default)]
+struct Options {
+    year: Option<u16>,
+    day: Option<u8>,
+    /// report the allocations made by each step of a solution
+    alloc_stats: bool,
+    /// print the results as json instead of text
+    json: bool,
+    /// only run solutions with this variant, using it instead of the default implementation
+    variant: Option<String>,
+}
+
+impl Options {
+    /// Parses `[year] [day] [--alloc-stats] [--json] [--variant NAME]`. A single number up to 25
+    /// is read as a day
+    fn parse(mut args: impl Iterator<Item = String>) -> Self {
+        const USAGE: &str = "Usage: [year] [day] [--alloc-stats] [--json] [--variant NAME]";
+
+        let mut options = Options::default();
+        let mut numbers = vec![];
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--alloc-stats" => options.alloc_stats = true,
+                "--json" => options.json = true,
+                "--variant" => match args.next() {
+                    Some(variant) => options.variant = Some(variant),
+                    None => {
+                        eprintln!("Missing variant name. {USAGE}");
+                        exit(1)
+                    }
+                },
+                number => match number.parse::<u16>() {
+                    Ok(number) => numbers.push(number),
+                    Err(_) => {
+                        eprintln!("Unknown argument '{arg}'. {USAGE}");
+                        exit(1)
+                    }
+                },
+            }
+        }
+
+        let day = |day: u16| match u8::try_from(day) {
+            Ok(day @ 1..=25) => day,
+            _ => {
+                eprintln!("Day {day} is out of range, days go from 1 to 25. {USAGE}");
+                exit(1)
+            }
+        };
+        let year = |year: u16| match year >= FIRST_AOC_YEAR {
+            true => year,
+            false => {
+                eprintln!(
+                    "Year {year} is out of range, the first year is {FIRST_AOC_YEAR}. {USAGE}"
+                );
+                exit(1)
+            }
+        };
+
+        match numbers[..] {
+            [] => {}
+            [number] if number <= 25 => options.day = Some(day(number)),
+            [number] => options.year = Some(year(number)),
+            [first, second] => {
+                options.year = Some(year(first));
+                options.day = Some(day(second));
+            }
+            _ => {
+                eprintln!("Too many numbers in {numbers:?}. {USAGE}");
+                exit(1)
+            }
+        }
+        options
+    }
+}
 
 fn main() {
     // Parse command line options
-    let year = match args().next() {
-        Some(arg) => arg.as_str().parse::<u16>().ok(),
-        None => None,
-    };
+    let options = Options::parse(args().skip(1));
+
+    let selected = empty()
+        .chain(solutions())
+        .filter(|solution| options.year == Some(solution.year) || options.year.is_none())
+        .filter(|solution| options.day == Some(solution.day) || options.day.is_none())
+        .collect::<Vec<_>>();
 
-    let day = match args().next_back() {
-        Some(arg) => arg.as_str().parse::<u8>().ok(),
-        None => None,
+    let has_variant = |solution: &Solution| match options.variant.as_deref() {
+        Some(DEFAULT_VARIANT) | None => true,
+        Some(variant) => solution.variants.contains(&variant),
     };
 
-    let solutions = empty()
-        .chain(solutions())
-        .filter(|solution| year == Some(solution.year) || year.is_none())
-        .filter(|solution| day == Some(solution.day) || day.is_none())
+    if !selected.is_empty() && !selected.iter().any(has_variant) {
+        eprintln!(
+            "Unknown variant '{}'. Besides '{DEFAULT_VARIANT}', the selected solutions have:",
+            options.variant.as_deref().unwrap_or_default()
+        );
+        let with_variants = selected.iter().filter(|s| !s.variants.is_empty());
+        if with_variants.clone().next().is_none() {
+            eprintln!("  no other variants");
+        }
+        for solution in with_variants {
+            let mut variants = solution.variants.clone();
+            variants.sort_unstable();
+            variants.dedup();
+            eprintln!(
+                "  {} day {:02}: {}",
+                solution.year,
+                solution.day,
+                variants.join(", ")
+            );
+        }
+        exit(1)
+    }
+
+    let solutions = selected
+        .into_iter()
+        .filter(|solution| has_variant(solution))
         .collect::<Vec<_>>();
 
+    if solutions.is_empty() {
+        eprintln!("No solutions match {options:?}");
+        exit(1)
+    }
+
+    let mut results = vec![];
+    let mut mismatches = 0;
+
     for Solution {
         year,
         day,
         input,
         callback,
+        ..
     } in solutions
     {
-        let time = Instant::now();
-        let (part_one, part_two) = (callback)(input);
-        let elapsed = time.elapsed();
+        let report = (callback)(input, options.variant.as_deref());
+        mismatches += report.mismatches().len();
+
+        if options.json {
+            results.push(to_json(year, day, &report, options.alloc_stats));
+        } else {
+            print_report(year, day, &report, options.alloc_stats);
+        }
+    }
 
-        let mut elapsed_str = format!("{} μs", elapsed.as_micros());
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
 
-        if elapsed.as_micros() <= 3 {
-            elapsed_str += &format!(" ({} ns)", elapsed.as_nanos());
-        };
+    if mismatches > 0 {
+        eprintln!("{RED}{mismatches} variant(s) disagree with the selected implementation{RESET}");
+        exit(1)
+    }
+}
+
+fn print_report(year: u16, day: u8, report: &Report, alloc_stats: bool) {
+    let elapsed = report.elapsed();
+    let mut elapsed_str = format!("{} μs", elapsed.as_micros());
+
+    if elapsed.as_micros() <= 3 {
+        elapsed_str += &format!(" ({} ns)", elapsed.as_nanos());
+    };
+
+    println!();
+    println!("✨ {BOLD}{YELLOW}{year} Day {day:02}{RESET} ✨");
+    println!();
+    println!(
+        "Solution to part 1 is: {YELLOW}{}{RESET}",
+        report.part_one().value
+    );
+    println!(
+        "Solution to part 2 is: {YELLOW}{}{RESET}",
+        report.part_two().value
+    );
+    println!("Took: {GREEN}{elapsed_str}{RESET}");
+
+    // compare the other implementations against the selected ones
+    for (part, answers) in [(1, &report.part_one), (2, &report.part_two)] {
+        if answers.len() < 2 {
+            continue;
+        }
+
+        for Answer {
+            variant,
+            value,
+            stats,
+        } in answers
+        {
+            let check = match value == &answers[0].value {
+                true => format!("{GREEN}✓{RESET}"),
+                false => format!("{RED}✗ {value}{RESET}"),
+            };
+            println!(
+                "Part {part} {BOLD}{variant}{RESET}: {check} {GREEN}{} μs{RESET}",
+                stats.elapsed.as_micros()
+            );
+        }
+    }
+
+    if alloc_stats {
+        for (step, Measurement { alloc, .. }) in [
+            ("Parse", report.parse),
+            ("Part 1", report.part_one().stats),
+            ("Part 2", report.part_two().stats),
+        ] {
+            println!(
+                "{step:>6}: {BLUE}{}{RESET} allocations, {BLUE}{}{RESET} allocated, {BLUE}{}{RESET} peak",
+                alloc.allocations,
+                bytes(alloc.bytes),
+                bytes(alloc.peak)
+            );
+        }
+    }
+}
+
+fn to_json(year: u16, day: u8, report: &Report, alloc_stats: bool) -> serde_json::Value {
+    let step = |measurement: &Measurement| {
+        let mut value = json!({ "elapsed_ns": nanos(measurement.elapsed) });
+
+        if alloc_stats {
+            value["allocations"] = json!(measurement.alloc.allocations);
+            value["bytes"] = json!(measurement.alloc.bytes);
+            value["peak_bytes"] = json!(measurement.alloc.peak);
+        }
+        value
+    };
+
+    let variants = |answers: &Vec<Answer>| {
+        answers
+            .iter()
+            .map(|answer| {
+                let mut value = step(&answer.stats);
+                value["variant"] = json!(answer.variant);
+                value["answer"] = json!(answer.value);
+                value
+            })
+            .collect::<Vec<_>>()
+    };
+
+    json!({
+        "year": year,
+        "day": day,
+        "part_one": report.part_one().value,
+        "part_two": report.part_two().value,
+        "elapsed_ns": nanos(report.elapsed()),
+        "parse": step(&report.parse),
+        "part_one_stats": step(&report.part_one().stats),
+        "part_two_stats": step(&report.part_two().stats),
+        "part_one_variants": variants(&report.part_one),
+        "part_two_variants": variants(&report.part_two),
+        "mismatches": report.mismatches().len(),
+    })
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
 
-        println!("");
-        println!("✨ {BOLD}{YELLOW}{year} Day {day:02}{RESET} ✨");
-        println!("");
-        println!("Solution to part 1 is: {YELLOW}{part_one}{RESET}");
-        println!("Solution to part 2 is: {YELLOW}{part_two}{RESET}");
-        println!("Took: {GREEN}{elapsed_str}{RESET}");
+/// Formats a number of bytes using binary prefixes
+fn bytes(bytes: usize) -> String {
+    match bytes {
+        b if b < 1 << 10 => format!("{b} B"),
+        b if b < 1 << 20 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
     }
 }
 
 fn solutions() -> Vec<Solution> {
-    vec![
-        solution!(year2023, day01),
-        solution!(year2023, day02),
-        solution!(year2023, day03),
-        solution!(year2023, day17),
-        solution!(year2023, day18),
-        solution!(year2023, day19),
-        solution!(year2023, day20),
-        solution!(year2023, day21),
-        solution!(year2023, day22),
-    ]
+    registry!(solution)
 }
@@ -1,15 +1,117 @@
-use std::{fmt::Debug, str::FromStr};
+use std::{
+    fmt::Debug,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use utils::alloc::{self, AllocStats};
 
 pub struct Solution {
     pub year: u16,
     pub day: u8,
     pub input: &'static str,
-    pub callback: fn(&str) -> (String, String),
+    /// names of the alternative implementations, besides the default one
+    pub variants: Vec<&'static str>,
+    pub callback: fn(&str, Option<&str>) -> Report,
+}
+
+/// Time and memory used by a single step of a solution
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub alloc: AllocStats,
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub variant: &'static str,
+    pub value: String,
+    pub stats: Measurement,
+}
+
+/// The answers of every variant of both parts. The selected variant of each part comes first
+#[derive(Debug)]
+pub struct Report {
+    pub parse: Measurement,
+    pub part_one: Vec<Answer>,
+    pub part_two: Vec<Answer>,
+}
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// A named implementation of a part, returning its answer and what it took to compute it
+pub type Variant<'a> = (&'static str, &'a dyn Fn() -> (String, Measurement));
+
+impl Report {
+    pub fn part_one(&self) -> &Answer {
+        &self.part_one[0]
+    }
+
+    pub fn part_two(&self) -> &Answer {
+        &self.part_two[0]
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.parse.elapsed + self.part_one().stats.elapsed + self.part_two().stats.elapsed
+    }
+
+    /// Variants whose answer differs from the selected variant, paired with their part
+    pub fn mismatches(&self) -> Vec<(u8, &Answer)> {
+        [(1, &self.part_one), (2, &self.part_two)]
+            .into_iter()
+            .flat_map(|(part, answers)| {
+                answers
+                    .iter()
+                    .filter(|answer| answer.value != answers[0].value)
+                    .map(move |answer| (part, answer))
+            })
+            .collect()
+    }
+}
+
+/// Runs `f`, measuring the time it takes and what it allocates
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let time = Instant::now();
+    let (result, alloc) = alloc::measure(f);
+    let elapsed = time.elapsed();
+
+    (result, Measurement { elapsed, alloc })
 }
 
+/// Runs every variant of a part, starting with the selected one (or the default one, if the
+/// part has no variant with that name). Variants may exist for only one of the parts, so names
+/// unknown to the whole solution are rejected by the runner before getting here
+pub fn run_variants(selected: Option<&str>, variants: &[Variant]) -> Vec<Answer> {
+    let selected = selected
+        .filter(|&name| variants.iter().any(|&(variant, _)| variant == name))
+        .unwrap_or(DEFAULT_VARIANT);
+
+    let (first, rest): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .partition(|&&(variant, _)| variant == selected);
+
+    first
+        .into_iter()
+        .chain(rest)
+        .map(|&(variant, run)| {
+            let (value, stats) = run();
+            Answer {
+                variant,
+                value,
+                stats,
+            }
+        })
+        .collect()
+}
+
+/// Creates a [`Solution`] for `year::day`. Alternative implementations of a part can be added
+/// as `name => function`, e.g. `solution!(year2023, day17, part_one: [fast => part_one_fast], part_two: [])`
 #[macro_export]
 macro_rules! solution {
     ($year:tt, $day:tt) => {
+        $crate::solution!($year, $day, part_one: [], part_two: [])
+    };
+    ($year:tt, $day:tt, part_one: [$($one:ident => $one_fn:ident),*], part_two: [$($two:ident => $two_fn:ident),*]) => {
         Solution {
             year: stringify!($year).safe_parse(),
             day: stringify!($day).safe_parse(),
@@ -21,17 +123,44 @@ macro_rules! solution {
                 stringify!($day),
                 ".txt"
             ]),
-            callback: |raw_input: &str| {
+            variants: vec![$(stringify!($one),)* $(stringify!($two),)*],
+            callback: |raw_input: &str, variant: Option<&str>| {
+                use $crate::setup::{measure, run_variants, Report, DEFAULT_VARIANT};
                 use $year::$day::*;
 
                 if raw_input.is_empty() {
                     panic!("Input is empty - did you forget to add your puzzle input?")
                 }
 
-                let input = input(raw_input);
-                let part_one = part_one(&input).to_string();
-                let part_two = part_two(&input).to_string();
-                (part_one, part_two)
+                let (input, parse) = measure(|| input(raw_input));
+
+                let part_one = run_variants(variant, &[
+                    (DEFAULT_VARIANT, &|| {
+                        let (answer, stats) = measure(|| part_one(&input));
+                        (answer.to_string(), stats)
+                    }),
+                    $((stringify!($one), &|| {
+                        let (answer, stats) = measure(|| $one_fn(&input));
+                        (answer.to_string(), stats)
+                    }),)*
+                ]);
+
+                let part_two = run_variants(variant, &[
+                    (DEFAULT_VARIANT, &|| {
+                        let (answer, stats) = measure(|| part_two(&input));
+                        (answer.to_string(), stats)
+                    }),
+                    $((stringify!($two), &|| {
+                        let (answer, stats) = measure(|| $two_fn(&input));
+                        (answer.to_string(), stats)
+                    }),)*
+                ]);
+
+                Report {
+                    parse,
+                    part_one,
+                    part_two,
+                }
             },
         }
     };
@@ -57,3 +186,58 @@ impl FilterExt for &str {
             .unwrap()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::setup::{run_variants, Measurement, Report, Variant, DEFAULT_VARIANT};
+
+    fn answer(value: &str) -> (String, Measurement) {
+        (value.to_string(), Measurement::default())
+    }
+
+    #[test]
+    fn run_variants_test() {
+        let variants: [Variant; 3] = [
+            (DEFAULT_VARIANT, &|| answer("1")),
+            ("fast", &|| answer("1")),
+            ("slow", &|| answer("2")),
+        ];
+
+        let names = |selected| {
+            run_variants(selected, &variants)
+                .iter()
+                .map(|a| a.variant)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["default", "fast", "slow"], names(None));
+        assert_eq!(vec!["slow", "default", "fast"], names(Some("slow")));
+        assert_eq!(vec!["default", "fast", "slow"], names(Some("missing")));
+    }
+
+    #[test]
+    fn mismatches_test() {
+        let report = Report {
+            parse: Measurement::default(),
+            part_one: run_variants(
+                None,
+                &[
+                    (DEFAULT_VARIANT, &|| answer("1")),
+                    ("fast", &|| answer("1")),
+                ],
+            ),
+            part_two: run_variants(
+                Some("fast"),
+                &[
+                    (DEFAULT_VARIANT, &|| answer("2")),
+                    ("fast", &|| answer("3")),
+                ],
+            ),
+        };
+
+        let mismatches = report.mismatches();
+        assert_eq!(1, mismatches.len());
+        assert_eq!(2, mismatches[0].0);
+        assert_eq!(DEFAULT_VARIANT, mismatches[0].1.variant);
+    }
+}
@@ -1,5 +1,7 @@
 //! # Day 2: Cube Conundrum
 
+use utils::parser::{CollectArray, ParseExt};
+
 type SolutionType = usize;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -8,15 +10,15 @@ pub struct Game(usize, usize, usize);
 pub fn input(raw: &str) -> Vec<Game> {
     raw.lines()
         .map(|line| {
-            line.split_ascii_whitespace()
-                .collect::<Vec<_>>()
-                .chunks(2)
-                .skip(1)
-                .fold(Game(0, 0, 0), |Game(r, g, b), color| {
-                    let amount = color[0].trim().parse::<usize>().unwrap();
-                    let color = color[1];
+            let (_, draws) = line.split_once(':').unwrap();
+
+            draws
+                .split([',', ';'])
+                .fold(Game(0, 0, 0), |Game(r, g, b), draw| {
+                    let [amount] = draw.unsigned::<usize>().collect_array().unwrap();
+                    let color = draw.trim_start_matches(|c: char| !c.is_ascii_alphabetic());
 
-                    match color.chars().nth(0) {
+                    match color.chars().next() {
                         Some('r') => Game(r.max(amount), g, b),
                         Some('g') => Game(r, g.max(amount), b),
                         Some('b') => Game(r, g, b.max(amount)),
@@ -2,7 +2,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use utils::point::Point;
+use utils::{grid::Grid, point::Point};
 
 type SolutionType = usize;
 
@@ -13,39 +13,38 @@ pub struct Schematic {
 }
 
 pub fn input(raw: &str) -> Schematic {
-    let mut symbols = vec![];
+    // lines may be indented, the schematic starts at the first non-whitespace character
+    let trimmed = raw.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+    let grid = Grid::parse_char(&trimmed).unwrap();
+
+    let symbols = grid
+        .iter_points()
+        .filter(|(_, &c)| !c.is_ascii_digit() && c != '.')
+        .map(|(point, &c)| (c, point))
+        .collect();
+
     let mut numbers_lookup: HashMap<Point, usize> = HashMap::new();
 
-    for (y, line) in raw.lines().enumerate() {
-        let mut line = line.trim().chars();
-        let mut num = vec![];
+    for (y, row) in grid.rows().enumerate() {
         let mut x = 0;
 
-        while let Some(next) = line.next() {
-            if next.is_ascii_digit() {
-                num.push(next)
-            } else {
-                if !num.is_empty() {
-                    let parsed_num = num.iter().collect::<String>().parse::<usize>().unwrap();
-                    for offset in 1..=num.len() {
-                        numbers_lookup.insert(Point::from((x - offset, y)), parsed_num);
-                    }
-                    num.clear()
-                }
+        while x < row.len() {
+            let len = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
 
-                if !next.is_ascii_digit() && next != '.' {
-                    symbols.push((next, Point::from((x, y))))
-                }
+            if len == 0 {
+                x += 1;
+                continue;
             }
-            x += 1;
-        }
 
-        // at the end of the line we need to save the num as well
-        if !num.is_empty() {
-            let parsed_num = num.iter().collect::<String>().parse::<usize>().unwrap();
-            for offset in 1..=num.len() {
-                numbers_lookup.insert(Point::from((x - offset, y)), parsed_num);
+            let parsed_num = row[x..x + len]
+                .iter()
+                .collect::<String>()
+                .parse::<usize>()
+                .unwrap();
+            for offset in x..x + len {
+                numbers_lookup.insert(Point::from((offset, y)), parsed_num);
             }
+            x += len;
         }
     }
 
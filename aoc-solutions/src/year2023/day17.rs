@@ -1,19 +1,17 @@
 //! # Day 17: Clumsy Crucible
 
-use std::{
-    cmp::Ordering,
-    collections::{BinaryHeap, HashSet},
-};
+use std::collections::HashSet;
 
 use utils::{
     grid::Grid,
-    point::{Point, DOWN, RIGHT},
+    point::{Point, DOWN, ORIGIN, RIGHT},
+    search::a_star,
 };
 
 type SolutionType = i64;
 
 pub fn input(raw: &str) -> Grid<i64> {
-    Grid::parse(raw)
+    Grid::parse(raw).unwrap()
 }
 
 pub fn part_one(grid: &Grid<i64>) -> SolutionType {
@@ -24,115 +22,122 @@ pub fn part_two(grid: &Grid<i64>) -> SolutionType {
     heat_loss::<4, 10>(grid).unwrap()
 }
 
-#[derive(Debug, PartialEq, Eq)]
+pub fn part_one_buckets(grid: &Grid<i64>) -> SolutionType {
+    heat_loss_buckets::<1, 3>(grid).unwrap()
+}
+
+pub fn part_two_buckets(grid: &Grid<i64>) -> SolutionType {
+    heat_loss_buckets::<4, 10>(grid).unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct State {
-    cost: i64,
     node: Point,
     dir: Point,   // direction you entered the node
     steps: usize, // number of steps taken in the same direction
 }
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+impl State {
+    /// The crucible starts in the top-left corner, moving either right or down
+    fn starts() -> [Self; 2] {
+        [RIGHT, DOWN].map(|dir| State {
+            node: ORIGIN,
+            dir,
+            steps: 0,
+        })
     }
-}
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn is_end<const MIN: usize>(&self, end: Point) -> bool {
+        self.node == end && self.steps >= MIN
     }
-}
 
-impl State {
-    fn neighbours<const MIN: usize, const MAX: usize>(&self, grid: &Grid<i64>) -> Vec<Self> {
-        let mut neighbours = vec![];
+    /// The states reachable in one step, along with the heat lost by moving there
+    fn successors<'a, const MIN: usize, const MAX: usize>(
+        &self,
+        grid: &'a Grid<i64>,
+    ) -> impl Iterator<Item = (Self, i64)> + 'a {
+        let current = *self;
+
+        current.node.cardinal().into_iter().filter_map(move |node| {
+            let straight = current.node + current.dir == node;
 
-        for node in self.node.cardinal() {
-            if self.node + self.dir != node && self.steps < MIN {
+            if !straight && current.steps < MIN {
                 // we need to move a MIN numver of steps before we can turn
-                continue;
+                return None;
             }
 
-            if self.node + self.dir == node && self.steps == MAX {
+            if straight && current.steps == MAX {
                 // if we've already moved MAX steps in this direction, we cannot continue
-                continue;
+                return None;
             }
 
-            if self.node - self.dir == node {
+            if current.node - current.dir == node {
                 // we came from this direction, no need to go back that way
-                continue;
+                return None;
             }
 
-            if grid.contains(node) {
-                let dir = node - self.node;
-                let cost = self.cost + grid[node];
-                let steps = if self.node + self.dir == node {
-                    self.steps + 1
-                } else {
-                    1
-                };
-
-                neighbours.push(State {
-                    node,
-                    cost,
-                    dir,
-                    steps,
-                })
-            }
-        }
-        neighbours
+            let steps = if straight { current.steps + 1 } else { 1 };
+            let dir = node - current.node;
+
+            grid.get(node)
+                .map(|&cost| (State { node, dir, steps }, cost))
+        })
     }
 }
 
 fn heat_loss<const MIN: usize, const MAX: usize>(grid: &Grid<i64>) -> Option<i64> {
-    let start = Point::new(0, 0);
     let end = Point::new(grid.width - 1, grid.height - 1);
 
-    let mut queue = BinaryHeap::new();
+    a_star(
+        State::starts(),
+        |state| state.successors::<MIN, MAX>(grid),
+        |state| state.node.manhattan(end),
+        |state| state.is_end::<MIN>(end),
+    )
+    .map(|found| found.cost)
+}
+
+/// Same search as [`heat_loss`], but since every step costs between 1 and 9 the priority
+/// queue can be replaced by a list of buckets indexed by cost (Dial's algorithm)
+fn heat_loss_buckets<const MIN: usize, const MAX: usize>(grid: &Grid<i64>) -> Option<i64> {
+    let end = Point::new(grid.width - 1, grid.height - 1);
+
+    let mut buckets: Vec<Vec<State>> = vec![State::starts().to_vec()];
     let mut seen = HashSet::new();
 
-    let right_cost = (1..=MIN).map(|x_off| grid[start + (RIGHT * x_off)]).sum();
-    let down_cost = (1..=MIN).map(|y_off| grid[start + (DOWN * y_off)]).sum();
-
-    // move right
-    queue.push(State {
-        node: start + (RIGHT * MIN),
-        cost: right_cost,
-        dir: RIGHT,
-        steps: MIN,
-    });
-
-    // move down
-    queue.push(State {
-        node: start + (DOWN * MIN),
-        cost: down_cost,
-        dir: DOWN,
-        steps: MIN,
-    });
-
-    while let Some(state) = queue.pop() {
-        if state.node == end && state.steps >= MIN {
-            return Some(state.cost);
-        }
+    let mut cost = 0;
+    while cost < buckets.len() {
+        while let Some(state) = buckets[cost].pop() {
+            if state.is_end::<MIN>(end) {
+                return Some(cost as i64);
+            }
 
-        for neighbour in state.neighbours::<MIN, MAX>(grid) {
-            // since the queue is ordered, if we encounter the node a second
-            // time the weight of this will be lower. We can therefore check
-            // if the insert succeeds and if not skip this neighbour
-            if seen.insert((neighbour.node, neighbour.dir, neighbour.steps)) {
-                queue.push(neighbour)
+            // every bucket is handled in order of cost, so the first visit is the cheapest
+            if !seen.insert(state) {
+                continue;
+            }
+
+            for (neighbour, loss) in state.successors::<MIN, MAX>(grid) {
+                push_bucket(&mut buckets, cost + loss as usize, neighbour);
             }
         }
+        cost += 1;
     }
     None
 }
 
+fn push_bucket(buckets: &mut Vec<Vec<State>>, cost: usize, state: State) {
+    if buckets.len() <= cost {
+        buckets.resize_with(cost + 1, Vec::new);
+    }
+    buckets[cost].push(state);
+}
+
 #[cfg(test)]
 mod test {
     use utils::point::Point;
 
-    use crate::year2023::day17::{input, part_one, part_two};
+    use crate::year2023::day17::{input, part_one, part_one_buckets, part_two, part_two_buckets};
 
     const EXAMPLE: &str = r"2413432311323
 3215453535623
@@ -168,4 +173,10 @@ mod test {
     fn part_two_test() {
         assert_eq!(94, part_two(&input(EXAMPLE)));
     }
+
+    #[test]
+    fn buckets_test() {
+        assert_eq!(102, part_one_buckets(&input(EXAMPLE)));
+        assert_eq!(94, part_two_buckets(&input(EXAMPLE)));
+    }
 }
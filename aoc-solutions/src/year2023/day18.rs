@@ -1,20 +1,15 @@
 //! # Day 18:
 
-use std::ops::Mul;
-
-use utils::point::{Point, DOWN, LEFT, ORIGIN, RIGHT, UP};
+use utils::{
+    direction::Direction,
+    geometry::{boundary_points, interior_points, polygon_from_moves},
+    point::Point,
+};
 
 type SolutionType = i64;
 
 fn as_point(c: char, n: usize) -> Point {
-    match c {
-        'R' | '0' => RIGHT,
-        'D' | '1' => DOWN,
-        'L' | '2' => LEFT,
-        'U' | '3' => UP,
-        _ => unreachable!(),
-    }
-    .mul(n)
+    Point::from(Direction::try_from(c).unwrap()) * n
 }
 
 pub fn input(raw: &str) -> (Vec<Point>, Vec<Point>) {
@@ -47,22 +42,9 @@ pub fn part_two(input: &(Vec<Point>, Vec<Point>)) -> SolutionType {
     area(&input.1)
 }
 
-fn area(points: &Vec<Point>) -> i64 {
-    let mut points = points.iter();
-
-    let mut corner = ORIGIN;
-    let mut area = 0;
-    let mut border = 0;
-
-    while let Some(&next) = points.next() {
-        area += corner.determinant(corner + next) as i64;
-        border += corner.manhattan(corner + next) as i64;
-        corner += next;
-    }
-
-    let internal_area = area.abs() / 2 - border / 2 + 1;
-
-    border + internal_area
+fn area(moves: &[Point]) -> i64 {
+    let polygon = polygon_from_moves(moves.iter().copied());
+    boundary_points(&polygon) + interior_points(&polygon)
 }
 
 #[cfg(test)]
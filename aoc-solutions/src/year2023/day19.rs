@@ -2,23 +2,18 @@
 
 use std::collections::HashMap;
 
+use utils::{
+    interval::{Cuboid, Interval},
+    parser::{parse_sections, CollectArray, ParseExt},
+};
+
 #[derive(Debug)]
 pub struct Part(u32, u32, u32, u32);
 
 impl Part {
     fn parse(input: &str) -> Self {
-        let parts = input
-            .split(',')
-            .map(|line| {
-                line.chars()
-                    .filter(|c| c.is_digit(10))
-                    .collect::<String>()
-                    .parse::<u32>()
-                    .unwrap()
-            })
-            .collect::<Vec<_>>();
-
-        Part(parts[0], parts[1], parts[2], parts[3])
+        let [x, m, a, s] = input.unsigned::<u32>().collect_array().unwrap();
+        Part(x, m, a, s)
     }
 }
 
@@ -77,10 +72,13 @@ impl<'a> Filter<'a> {
 }
 
 pub fn input(raw: &str) -> (HashMap<String, Vec<Filter>>, Vec<Part>) {
-    let (prefix, suffix) = raw.split_once("\n\n").unwrap();
+    parse_sections(raw, (workflows, parts)).unwrap_or_else(|e| panic!("{e}"))
+}
+
+fn workflows(section: &str) -> HashMap<String, Vec<Filter<'_>>> {
     let mut workflows = HashMap::new();
 
-    for line in prefix.lines() {
+    for line in section.lines() {
         let mut sections = line.split(['{', '}', ',']).filter(|l| !l.is_empty());
         let key = sections.next().unwrap().to_string();
 
@@ -90,13 +88,11 @@ pub fn input(raw: &str) -> (HashMap<String, Vec<Filter>>, Vec<Part>) {
         }
         workflows.insert(key, filters);
     }
+    workflows
+}
 
-    let parts = suffix
-        .lines()
-        .map(|line| Part::parse(line))
-        .collect::<Vec<_>>();
-
-    (workflows, parts)
+fn parts(section: &str) -> Vec<Part> {
+    section.lines().map(Part::parse).collect::<Vec<_>>()
 }
 
 pub fn part_one((workflows, parts): &(HashMap<String, Vec<Filter>>, Vec<Part>)) -> u32 {
@@ -128,19 +124,16 @@ pub fn part_one((workflows, parts): &(HashMap<String, Vec<Filter>>, Vec<Part>))
     sum
 }
 
-pub fn part_two((workflows, _): &(HashMap<String, Vec<Filter>>, Vec<Part>)) -> u64 {
+pub fn part_two((workflows, _): &(HashMap<String, Vec<Filter>>, Vec<Part>)) -> u128 {
     let mut result = 0;
     let mut queue = vec![];
 
     // add ranges (1..=4000) for each char 'x', 'm', 'a', 's'
-    queue.push(("in", 0, [(1, 4000); 4]));
+    queue.push(("in", 0, Cuboid::new([Interval::new(1, 4000); 4])));
 
     while let Some((key, index, part)) = queue.pop() {
         if key == "A" {
-            result += part
-                .iter()
-                .map(|(start, end)| (end - start + 1) as u64)
-                .product::<u64>(); // calculate the product of all the ranges
+            result += part.volume(); // the product of all the ranges
             continue;
         }
         if key == "R" {
@@ -152,39 +145,21 @@ pub fn part_two((workflows, _): &(HashMap<String, Vec<Filter>>, Vec<Part>)) -> u
         match workflows[key][index] {
             Filter::Send(next) => queue.push((next, 0, part)),
             Filter::Less(c_idx, v, next) => {
-                let (start_idx, end_idx) = part[c_idx];
-
-                if start_idx >= v {
-                    queue.push((key, index + 1, part));
-                } else if end_idx < v {
-                    queue.push((next, 0, part));
-                } else {
-                    // open ended ranges, split in two and push both ranges
-                    let mut first = part;
-                    first[c_idx] = (start_idx, v - 1);
-                    queue.push((next, 0, first));
-
-                    let mut second = part;
-                    second[c_idx] = (v, end_idx);
-                    queue.push((key, index + 1, second));
+                let (matching, rest) = part.split_at(c_idx, v);
+                if let Some(matching) = matching {
+                    queue.push((next, 0, matching));
+                }
+                if let Some(rest) = rest {
+                    queue.push((key, index + 1, rest));
                 }
             }
             Filter::Greater(c_idx, v, next) => {
-                let (start_idx, end_idx) = part[c_idx];
-
-                if end_idx <= v {
-                    queue.push((key, index + 1, part));
-                } else if start_idx > v {
-                    queue.push((next, 0, part));
-                } else {
-                    // open ended ranges, split in two and push both ranges
-                    let mut first = part;
-                    first[c_idx] = (start_idx, v);
-                    queue.push((key, index + 1, first));
-
-                    let mut second = part;
-                    second[c_idx] = (v + 1, end_idx);
-                    queue.push((next, 0, second));
+                let (rest, matching) = part.split_at(c_idx, v + 1);
+                if let Some(rest) = rest {
+                    queue.push((key, index + 1, rest));
+                }
+                if let Some(matching) = matching {
+                    queue.push((next, 0, matching));
                 }
             }
         }
@@ -234,6 +209,7 @@ hdj{m>838:A,pv}
     #[test]
     fn part_one_test() {
         assert_eq!(19114, part_one(&input(EXAMPLE)));
+        assert_eq!(19114, part_one(&input(&EXAMPLE.replace('\n', "\r\n"))));
     }
 
     #[test]
@@ -3,7 +3,10 @@
 use core::fmt;
 use std::collections::{HashMap, VecDeque};
 
-use utils::num::Integer;
+use utils::{
+    num::lcm_all,
+    parser::{one_of, optional, pair, parse_all, preceded, sep_by, tag, word},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Pulse {
@@ -42,14 +45,17 @@ pub enum Module<'a> {
 
 impl<'a> Module<'a> {
     fn parse(line: &'a str) -> (String, Self) {
-        let (prefix, suffix) = line.split_once(" -> ").unwrap();
-        let next = suffix.split(',').map(|n| n.trim()).collect::<Vec<_>>();
-        let id = prefix[1..].to_string();
-
-        match prefix.chars().nth(0).unwrap() {
-            '%' => (id, Module::FlipFlop(State::Off, next)),
-            '&' => (id, Module::Conjunction(HashMap::new(), next)),
-            _ => (prefix.to_string(), Module::Broadcaster(next)),
+        let module = pair(
+            pair(optional(one_of("%&")), word()),
+            preceded(tag(" -> "), sep_by(word(), tag(", "))),
+        );
+        let ((kind, id), next) = parse_all(module, line).unwrap_or_else(|e| panic!("{e}"));
+        let id = id.to_string();
+
+        match kind {
+            Some('%') => (id, Module::FlipFlop(State::Off, next)),
+            Some('&') => (id, Module::Conjunction(HashMap::new(), next)),
+            _ => (id, Module::Broadcaster(next)),
         }
     }
 
@@ -116,7 +122,7 @@ pub fn part_one(input: &HashMap<String, Module>) -> usize {
     find_pulses(&mut input.clone())
 }
 
-pub fn part_two(input: &HashMap<String, Module>) -> u128 {
+pub fn part_two(input: &HashMap<String, Module>) -> u64 {
     find_cycles(input)
 }
 
@@ -180,7 +186,7 @@ fn parents(modules: &HashMap<String, Module>, child: &str) -> Vec<String> {
     parents
 }
 
-pub fn find_cycles(lookup: &HashMap<String, Module>) -> u128 {
+pub fn find_cycles(lookup: &HashMap<String, Module>) -> u64 {
     let mut cycles = Vec::new();
 
     // first find the parent of the 'rx' module
@@ -194,10 +200,10 @@ pub fn find_cycles(lookup: &HashMap<String, Module>) -> u128 {
         cycles.push(next_cycle);
     }
 
-    cycles.iter().fold(1, |acc, &next| acc.lcm(&next))
+    lcm_all(cycles).expect("The cycles never align within a u64")
 }
 
-pub fn find_module_cycle(lookup: &mut HashMap<String, Module>, module: String) -> u128 {
+pub fn find_module_cycle(lookup: &mut HashMap<String, Module>, module: String) -> u64 {
     let mut button_presses = 0;
 
     loop {
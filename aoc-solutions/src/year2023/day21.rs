@@ -1,11 +1,14 @@
 //! # Day 21: Step Counter
 
-use std::collections::{HashSet, VecDeque};
-
-use utils::{grid::Grid, point::Point};
+use utils::{
+    grid::Grid,
+    neighbourhood::{Boundary, Neighbourhood},
+    point::Point,
+    search::reachable_in_exactly,
+};
 
 pub fn input(raw: &str) -> (Grid<char>, Point) {
-    let gardens = Grid::parse_char(raw);
+    let gardens = Grid::parse_char(raw).unwrap();
     let start = gardens.find('S').unwrap();
     (gardens, start)
 }
@@ -70,43 +73,25 @@ pub fn expand(garden: &Grid<char>, start: &Point, steps: i64) -> usize {
 }
 
 pub fn explore(garden: &Grid<char>, start: &Point, steps: i64) -> usize {
-    let mut sum = HashSet::new();
-    let mut visisted: HashSet<Point> = HashSet::from([start.to_owned()]);
-
-    let mut queue = VecDeque::from([(start.to_owned(), steps)]);
-
-    while let Some((pos, steps)) = queue.pop_front() {
-        if steps % 2 == 0 {
-            sum.insert(pos);
-        }
-
-        if steps == 0 {
-            continue; // empty the queue before exiting, but no need to calculate stuff
-        }
-
-        for neighbour in pos.cardinal() {
-            if visisted.contains(&neighbour) || !garden.contains(neighbour) {
-                continue;
-            }
-
-            if let Some(next) = match garden[neighbour] {
-                c if c != '#' => Some(neighbour),
-                _ => None,
-            } {
-                visisted.insert(next);
-                queue.push_back((next, steps - 1));
-            }
-        }
-    }
+    explore_with(garden, start, steps, Boundary::Clip)
+}
 
-    sum.len()
+/// The gardens reachable in exactly `steps`. With [`Boundary::Infinite`] the garden repeats
+/// forever, which is too slow for part two but useful to validate [`expand`]
+pub fn explore_with(garden: &Grid<char>, start: &Point, steps: i64, boundary: Boundary) -> usize {
+    reachable_in_exactly([*start], steps as usize, |&pos| {
+        garden
+            .neighbours_with(pos, Neighbourhood::Cardinal, boundary)
+            .filter(|&(_, c)| c != '#')
+            .map(|(neighbour, _)| neighbour)
+    })
 }
 
 #[cfg(test)]
 mod test {
-    use utils::point::Point;
+    use utils::{neighbourhood::Boundary, point::Point};
 
-    use crate::year2023::day21::{explore, input};
+    use crate::year2023::day21::{explore, explore_with, input};
 
     const EXAMPLE: &str = r"...........
 .....###.#.
@@ -133,4 +118,14 @@ mod test {
         let (gardens, start) = input(EXAMPLE);
         assert_eq!(16, explore(&gardens, &start, 6));
     }
+
+    #[test]
+    fn explore_tiled_test() {
+        let (gardens, start) = input(EXAMPLE);
+        let tiled = |steps| explore_with(&gardens, &start, steps, Boundary::Infinite);
+        assert_eq!(16, tiled(6));
+        assert_eq!(50, tiled(10));
+        assert_eq!(1594, tiled(50));
+        assert_eq!(6536, tiled(100));
+    }
 }
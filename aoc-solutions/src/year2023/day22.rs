@@ -6,27 +6,13 @@ use std::{
     usize,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Range(usize, usize);
-
-impl Range {
-    fn intersects(self, other: Self) -> bool {
-        // see https://stackoverflow.com/a/3269471
-        self.0 <= other.1 && other.0 <= self.1
-    }
-}
-
-impl fmt::Display for Range {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}..{}", self.0, self.1)
-    }
-}
+use utils::{interval::Interval, point3::Point3};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Brick {
-    x: Range,
-    y: Range,
-    z: Range,
+    x: Interval<usize>,
+    y: Interval<usize>,
+    z: Interval<usize>,
 }
 
 #[derive(Debug)]
@@ -37,24 +23,23 @@ pub struct Supports {
 
 impl Brick {
     fn parse(line: &str) -> Self {
-        let coords = line
-            .split([',', '~'])
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect::<Vec<_>>();
+        let (start, end) = line.split_once('~').unwrap();
+        let start = start.parse::<Point3>().unwrap();
+        let end = end.parse::<Point3>().unwrap();
 
         Brick {
-            x: Range(coords[0], coords[3]),
-            y: Range(coords[1], coords[4]),
-            z: Range(coords[2], coords[5]),
+            x: Interval::new(start.x as usize, end.x as usize),
+            y: Interval::new(start.y as usize, end.y as usize),
+            z: Interval::new(start.z as usize, end.z as usize),
         }
     }
 
     fn top(self) -> usize {
-        self.z.1
+        self.z.end
     }
 
     fn bottom(self) -> usize {
-        self.z.0
+        self.z.start
     }
 
     fn height(self) -> usize {
@@ -62,7 +47,7 @@ impl Brick {
     }
 
     fn intersects_xy(self, other: &Self) -> bool {
-        self.x.intersects(other.x) && self.y.intersects(other.y)
+        self.x.intersects(&other.x) && self.y.intersects(&other.y)
     }
 }
 
@@ -129,7 +114,7 @@ fn apply_gravity(bricks: &mut Vec<Brick>) -> Vec<Brick> {
             }
         }
         let brick_height = brick.height();
-        brick.z = Range(bottom, bottom + brick_height);
+        brick.z = Interval::new(bottom, bottom + brick_height);
         new_bricks.push(*brick)
     }
     new_bricks
@@ -160,7 +145,9 @@ fn supports(bricks: &Vec<Brick>) -> Supports {
 
 #[cfg(test)]
 mod test {
-    use crate::year2023::day22::{input, part_one, part_two, Brick, Range};
+    use utils::interval::Interval;
+
+    use crate::year2023::day22::{input, part_one, part_two, Brick};
 
     const EXAMPLE: &str = r"1,0,1~1,2,1
 0,0,2~2,0,2
@@ -175,9 +162,9 @@ mod test {
         let bricks = input(EXAMPLE);
         assert_eq!(
             Brick {
-                x: Range(1, 1),
-                y: Range(0, 2),
-                z: Range(1, 1),
+                x: Interval::new(1, 1),
+                y: Interval::new(0, 2),
+                z: Interval::new(1, 1),
             },
             bricks[0]
         );
@@ -0,0 +1,129 @@
+//! A counting global allocator, used to profile the memory usage of solutions.
+//!
+//! The allocator has to be registered by the binary using it:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//! ```
+//!
+//! Without it, [`measure`] reports no allocations at all.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// number of allocations (including reallocations)
+    pub allocations: usize,
+    /// total number of bytes requested, counting only the growth of reallocations
+    pub bytes: usize,
+    /// highest heap usage above the heap usage at the start of the measurement
+    pub peak: usize,
+}
+
+fn grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size(), Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size(), Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            if new_size > layout.size() {
+                BYTES.fetch_add(new_size - layout.size(), Relaxed);
+                grow(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f` and reports the allocations it made. The counters are global, so allocations
+/// made by other threads in the meantime are included as well.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let bytes = BYTES.load(Relaxed);
+    let baseline = CURRENT.load(Relaxed);
+    PEAK.store(baseline, Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(baseline),
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::alloc::{measure, CountingAllocator};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn measure_test() {
+        let (sum, stats) = measure(|| {
+            let numbers = vec![1u64; 1024];
+            numbers.iter().sum::<u64>()
+        });
+
+        assert_eq!(1024, sum);
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 1024 * 8);
+        assert!(stats.peak >= 1024 * 8);
+    }
+
+    #[test]
+    fn realloc_test() {
+        let (_, stats) = measure(|| {
+            let mut numbers: Vec<u8> = Vec::with_capacity(1);
+            numbers.extend([0; 4096]);
+            numbers
+        });
+
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 4096);
+    }
+}
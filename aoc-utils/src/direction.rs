@@ -1,3 +1,7 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::point::{Point, DOWN, LEFT, RIGHT, UP};
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
 pub enum Direction {
     UP,
@@ -5,3 +9,295 @@ pub enum Direction {
     LEFT,
     RIGHT,
 }
+
+/// The 4 cardinal and 4 diagonal directions, by compass point with north pointing up
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectionError {
+    /// the point is not one step away from the origin
+    NotAdjacent(Point),
+    Unknown(String),
+}
+
+impl fmt::Display for DirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DirectionError::NotAdjacent(point) => write!(f, "{point} is not a unit direction"),
+            DirectionError::Unknown(s) => write!(f, "'{s}' is not a direction"),
+        }
+    }
+}
+
+impl Error for DirectionError {}
+
+impl Direction {
+    /// All directions, clockwise starting from up
+    pub const ALL: [Direction; 4] = [
+        Direction::UP,
+        Direction::RIGHT,
+        Direction::DOWN,
+        Direction::LEFT,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            Direction::UP => 0,
+            Direction::RIGHT => 1,
+            Direction::DOWN => 2,
+            Direction::LEFT => 3,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::UP => UP,
+            Direction::DOWN => DOWN,
+            Direction::LEFT => LEFT,
+            Direction::RIGHT => RIGHT,
+        }
+    }
+}
+
+/// A move of a number of steps in a direction
+impl From<(Direction, i64)> for Point {
+    fn from((direction, steps): (Direction, i64)) -> Self {
+        Point::from(direction) * steps
+    }
+}
+
+impl TryFrom<Point> for Direction {
+    type Error = DirectionError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Direction::ALL
+            .into_iter()
+            .find(|&direction| Point::from(direction) == point)
+            .ok_or(DirectionError::NotAdjacent(point))
+    }
+}
+
+/// Parses the common encodings: `U/D/L/R`, compass points `N/S/E/W`, arrows `^v<>`
+/// and digits `0-3` (right, down, left, up)
+impl TryFrom<char> for Direction {
+    type Error = DirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' | '3' => Ok(Direction::UP),
+            'D' | 'S' | 'v' | '1' => Ok(Direction::DOWN),
+            'L' | 'W' | '<' | '2' => Ok(Direction::LEFT),
+            'R' | 'E' | '>' | '0' => Ok(Direction::RIGHT),
+            _ => Err(DirectionError::Unknown(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(DirectionError::Unknown(s.to_string())),
+        }
+    }
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&d| d == self).unwrap()
+    }
+
+    /// Turns 45° clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns 45° counter-clockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::UP => Direction8::N,
+            Direction::DOWN => Direction8::S,
+            Direction::LEFT => Direction8::W,
+            Direction::RIGHT => Direction8::E,
+        }
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(direction: Direction8) -> Self {
+        match direction {
+            Direction8::N => UP,
+            Direction8::NE => UP + RIGHT,
+            Direction8::E => RIGHT,
+            Direction8::SE => DOWN + RIGHT,
+            Direction8::S => DOWN,
+            Direction8::SW => DOWN + LEFT,
+            Direction8::W => LEFT,
+            Direction8::NW => UP + LEFT,
+        }
+    }
+}
+
+impl TryFrom<Point> for Direction8 {
+    type Error = DirectionError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Direction8::ALL
+            .into_iter()
+            .find(|&direction| Point::from(direction) == point)
+            .ok_or(DirectionError::NotAdjacent(point))
+    }
+}
+
+/// Parses compass points, e.g. `N` or `SW`
+impl FromStr for Direction8 {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" => Ok(Direction8::N),
+            "NE" => Ok(Direction8::NE),
+            "E" => Ok(Direction8::E),
+            "SE" => Ok(Direction8::SE),
+            "S" => Ok(Direction8::S),
+            "SW" => Ok(Direction8::SW),
+            "W" => Ok(Direction8::W),
+            "NW" => Ok(Direction8::NW),
+            _ => Err(DirectionError::Unknown(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        direction::{Direction, Direction8, DirectionError},
+        point::{Point, DOWN, LEFT, RIGHT, UP},
+    };
+
+    #[test]
+    fn turn_test() {
+        assert_eq!(Direction::RIGHT, Direction::UP.turn_right());
+        assert_eq!(Direction::LEFT, Direction::UP.turn_left());
+        assert_eq!(Direction::UP, Direction::LEFT.turn_right());
+        assert_eq!(Direction::DOWN, Direction::UP.reverse());
+        assert_eq!(Direction::RIGHT, Direction::LEFT.reverse());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_left().turn_left());
+        }
+    }
+
+    #[test]
+    fn point_test() {
+        assert_eq!(UP, Point::from(Direction::UP));
+        assert_eq!(LEFT, Point::from(Direction::LEFT));
+        assert_eq!(Point::new(0, 3), Point::from((Direction::DOWN, 3)));
+        assert_eq!(Ok(Direction::DOWN), Direction::try_from(DOWN));
+        assert_eq!(
+            Err(DirectionError::NotAdjacent(Point::new(1, 1))),
+            Direction::try_from(Point::new(1, 1))
+        );
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                -1,
+                Point::from(direction).determinant(Point::from(direction.turn_left()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_test() {
+        for (encodings, expected) in [
+            ("UN^3", Direction::UP),
+            ("DSv1", Direction::DOWN),
+            ("LW<2", Direction::LEFT),
+            ("RE>0", Direction::RIGHT),
+        ] {
+            for c in encodings.chars() {
+                assert_eq!(Ok(expected), Direction::try_from(c));
+            }
+        }
+
+        assert_eq!(Ok(Direction::RIGHT), "R".parse());
+        assert!("RR".parse::<Direction>().is_err());
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn direction8_test() {
+        assert_eq!(Direction8::NE, Direction8::N.turn_right());
+        assert_eq!(Direction8::NW, Direction8::N.turn_left());
+        assert_eq!(Direction8::SW, Direction8::NE.reverse());
+        assert!(Direction8::SE.is_diagonal());
+        assert!(!Direction8::E.is_diagonal());
+
+        assert_eq!(UP + RIGHT, Point::from(Direction8::NE));
+        assert_eq!(Ok(Direction8::SW), Direction8::try_from(DOWN + LEFT));
+        assert_eq!(Direction8::W, Direction8::from(Direction::LEFT));
+        assert_eq!(Ok(Direction8::SE), "SE".parse());
+        assert_eq!(
+            8,
+            Direction8::ALL
+                .iter()
+                .map(|&d| Point::from(d))
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+    }
+}
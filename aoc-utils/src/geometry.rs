@@ -0,0 +1,169 @@
+//! Lattice polygons, given as their vertices in order. The polygon is closed implicitly:
+//! the last vertex connects back to the first.
+
+use crate::{
+    num::Integer,
+    point::{Point, ORIGIN},
+};
+
+/// The edges of the polygon, including the closing one
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Twice the area of the polygon, which is always a whole number for lattice polygons
+pub fn twice_area(vertices: &[Point]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| a.determinant(b))
+        .sum::<i64>()
+        .abs()
+}
+
+/// The area of the polygon using the shoelace formula, rounded down when the area of the
+/// polygon is not whole (see [`twice_area`])
+pub fn shoelace_area(vertices: &[Point]) -> i64 {
+    twice_area(vertices) / 2
+}
+
+/// The number of lattice points on the edges of the polygon, which do not need to be
+/// horizontal or vertical
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| {
+            let d = (b - a).abs();
+            d.x.gcd(&d.y)
+        })
+        .sum()
+}
+
+/// The number of lattice points strictly inside the polygon, using Pick's theorem
+pub fn interior_points(vertices: &[Point]) -> i64 {
+    (twice_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+/// Whether the point is inside the polygon or on one of its edges
+pub fn point_in_polygon(vertices: &[Point], point: Point) -> bool {
+    let mut inside = false;
+
+    for (a, b) in edges(vertices) {
+        let on_line = (b - a).determinant(point - a) == 0;
+        let (low, high) = (a.component_min(b), a.component_max(b));
+        if on_line && point.component_min(low) == low && point.component_max(high) == high {
+            return true;
+        }
+
+        // cast a ray to the right, counting the edges it crosses. The half-open comparison
+        // makes sure a vertex on the ray is only counted once
+        if (a.y > point.y) != (b.y > point.y) {
+            let crossing = (b - a).determinant(point - a);
+            if (crossing > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// The vertices of the polygon traced by following the moves from the origin, where a move
+/// is anything that converts into an offset, e.g. `(Direction, steps)` or a [`Point`]
+pub fn polygon_from_moves<M: Into<Point>>(moves: impl IntoIterator<Item = M>) -> Vec<Point> {
+    let mut corner = ORIGIN;
+    let mut vertices = vec![corner];
+
+    for step in moves {
+        corner += step.into();
+        vertices.push(corner);
+    }
+
+    // a closed path ends where it started, which is already the first vertex
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+    vertices
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        direction::Direction,
+        geometry::{
+            boundary_points, interior_points, point_in_polygon, polygon_from_moves, shoelace_area,
+            twice_area,
+        },
+        point::Point,
+    };
+
+    fn square() -> Vec<Point> {
+        vec![
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 4),
+            Point::new(0, 4),
+        ]
+    }
+
+    #[test]
+    fn area_test() {
+        assert_eq!(16, shoelace_area(&square()));
+
+        // the orientation does not matter
+        let mut reversed = square();
+        reversed.reverse();
+        assert_eq!(16, shoelace_area(&reversed));
+
+        let triangle = [Point::new(0, 0), Point::new(3, 0), Point::new(0, 1)];
+        assert_eq!(3, twice_area(&triangle));
+        assert_eq!(1, shoelace_area(&triangle));
+    }
+
+    #[test]
+    fn pick_test() {
+        assert_eq!(16, boundary_points(&square()));
+        assert_eq!(9, interior_points(&square()));
+
+        // diagonal edges only pass through some lattice points
+        let triangle = [Point::new(0, 0), Point::new(4, 0), Point::new(0, 6)];
+        assert_eq!(4 + 2 + 6, boundary_points(&triangle));
+        assert_eq!(12 - 6 + 1, interior_points(&triangle));
+    }
+
+    #[test]
+    fn point_in_polygon_test() {
+        let polygon = [
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 4),
+            Point::new(2, 2),
+            Point::new(0, 4),
+        ];
+
+        assert!(point_in_polygon(&polygon, Point::new(1, 1)));
+        assert!(point_in_polygon(&polygon, Point::new(3, 3)));
+        assert!(point_in_polygon(&polygon, Point::new(2, 0))); // edge
+        assert!(point_in_polygon(&polygon, Point::new(4, 4))); // vertex
+        assert!(point_in_polygon(&polygon, Point::new(1, 3))); // diagonal edge
+        assert!(!point_in_polygon(&polygon, Point::new(2, 3))); // in the notch
+        assert!(!point_in_polygon(&polygon, Point::new(5, 2)));
+        assert!(!point_in_polygon(&polygon, Point::new(-1, 2)));
+    }
+
+    #[test]
+    fn polygon_from_moves_test() {
+        let moves = [
+            (Direction::RIGHT, 4),
+            (Direction::DOWN, 4),
+            (Direction::LEFT, 4),
+            (Direction::UP, 4),
+        ];
+        assert_eq!(square(), polygon_from_moves(moves));
+
+        let open = polygon_from_moves([Point::new(2, 0), Point::new(0, 3)]);
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(2, 0), Point::new(2, 3)],
+            open
+        );
+    }
+}
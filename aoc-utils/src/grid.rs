@@ -1,6 +1,17 @@
-use std::ops::Index;
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    error::Error,
+    fmt,
+    iter::successors,
+    ops::{Index, IndexMut},
+};
 
-use crate::point::Point;
+use crate::{
+    ansi::RESET,
+    neighbourhood::{Boundary, Neighbourhood, Neighbours},
+    point::{Point, DOWN, ORIGIN},
+};
 
 pub struct Grid<T> {
     pub width: i64, // to remain consistent with point
@@ -8,93 +19,655 @@ pub struct Grid<T> {
     pub data: Vec<T>,
 }
 
+/// Errors when parsing a grid. Rows and columns are 0-indexed, like the points of the grid
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    Unmappable {
+        row: usize,
+        column: usize,
+        found: char,
+        reason: String,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "Cannot parse a grid from empty input"),
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {row} has {found} columns, but the rows above have {expected}"
+            ),
+            GridError::Unmappable {
+                row,
+                column,
+                found,
+                reason,
+            } => write!(
+                f,
+                "Cannot map {found:?} at row {row}, column {column}: {reason}"
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl<T> Grid<T> {
+    /// Parses a grid with one row per line, mapping every char with `f`. Trailing newlines
+    /// are ignored and both `\n` and `\r\n` line endings are supported.
+    pub fn parse_with<E: fmt::Display>(
+        input: &str,
+        f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, GridError> {
+        Self::from_rows(
+            input.trim_end_matches(['\r', '\n']).lines().map(str::chars),
+            f,
+        )
+    }
+
+    fn from_rows<C: Copy + Into<char>, E: fmt::Display>(
+        rows: impl Iterator<Item = impl Iterator<Item = C>>,
+        mut f: impl FnMut(C) -> Result<T, E>,
+    ) -> Result<Self, GridError> {
+        let mut data = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, cells) in rows.enumerate() {
+            let start = data.len();
+
+            for (column, cell) in cells.enumerate() {
+                let value = f(cell).map_err(|e| GridError::Unmappable {
+                    row,
+                    column,
+                    found: cell.into(),
+                    reason: e.to_string(),
+                })?;
+                data.push(value);
+            }
+
+            let found = data.len() - start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::Ragged {
+                        row,
+                        expected,
+                        found,
+                    })
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            None | Some(0) => Err(GridError::Empty),
+            Some(width) => Ok(Grid {
+                width: width as i64,
+                height,
+                data,
+            }),
+        }
+    }
+}
+
 impl Grid<i64> {
-    pub fn parse(input: &str) -> Self {
-        let raw = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_digit(10).unwrap() as i64)
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
+    /// Parses a grid of single digits
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|digit| digit as i64)
+                .ok_or("not a digit")
+        })
+    }
+}
 
-        let width = raw[0].len() as i64;
-        let height = raw.len() as i64;
-        let data = raw
-            .iter()
-            .flatten()
-            .map(|e| e.to_owned())
-            .collect::<Vec<_>>();
+impl Grid<char> {
+    pub fn parse_char(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, Ok::<char, Infallible>)
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of raw bytes. Faster than [`Grid::parse_char`] as no utf-8 decoding is
+    /// needed, which is sufficient for the ascii inputs of most puzzles
+    pub fn parse_bytes(input: &str) -> Result<Self, GridError> {
+        let rows = input
+            .trim_end_matches(['\r', '\n'])
+            .as_bytes()
+            .split(|&b| b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line).iter().copied());
+
+        Self::from_rows(rows, Ok::<u8, Infallible>)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` x `height` grid with every cell set to `default`
+    pub fn new(width: i64, height: i64, default: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width >= 0 && height >= 0, "Grid size cannot be negative");
 
         Grid {
             width,
             height,
-            data,
+            data: vec![default; (width * height) as usize],
         }
     }
-}
 
-impl Grid<char> {
-    pub fn parse_char(input: &str) -> Self {
-        let raw = input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-
-        let width = raw[0].len() as i64;
-        let height = raw.len() as i64;
-        let data = raw
-            .iter()
-            .flatten()
-            .map(|e| e.to_owned())
-            .collect::<Vec<_>>();
+    /// Creates a `width` x `height` grid, computing every cell from its point
+    pub fn from_fn(width: i64, height: i64, f: impl FnMut(Point) -> T) -> Self {
+        assert!(width >= 0 && height >= 0, "Grid size cannot be negative");
 
         Grid {
             width,
             height,
-            data,
+            data: (0..height)
+                .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+                .map(f)
+                .collect(),
         }
     }
-}
 
-impl<T: Copy + PartialEq> Grid<T> {
+    /// The index of a point in `data`, or `None` if it is outside the grid
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| (self.width * point.y + point.x) as usize)
+    }
+
     pub fn contains(&self, point: Point) -> bool {
         point.x >= 0 && point.x < self.width && point.y >= 0 && point.y < self.height
     }
 
-    pub fn neighbours(&self, point: Point) -> Vec<(Point, T)> {
-        point
-            .cardinal()
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.data[index])
+    }
+
+    /// Creates a grid of the same size, mapping every cell with `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Swaps the values of two cells. Panics if either point is outside the grid
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let a = self.checked_index(a);
+        let b = self.checked_index(b);
+        self.data.swap(a, b);
+    }
+
+    fn checked_index(&self, point: Point) -> usize {
+        self.index_of(point).unwrap_or_else(|| {
+            panic!(
+                "Point {point} is outside the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+
+    /// The point of an index in `data`
+    fn point_of(&self, index: usize) -> Point {
+        Point::new(index as i64 % self.width, index as i64 / self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a size of 0, but an empty grid has no data to chunk anyway
+        self.data.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.ray(Point::new(x, 0), DOWN).map(|(_, value)| value))
+    }
+
+    /// Every cell with its point, row by row
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.data
             .iter()
-            .filter(|&p| self.contains(*p))
-            .map(|&p| (p, self[p]))
-            .collect::<Vec<_>>()
+            .enumerate()
+            .map(|(index, value)| (self.point_of(index), value))
+    }
+
+    /// The cells from `start`, repeatedly moving by `step` until leaving the grid
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        successors(Some(start), move |&point| Some(point + step))
+            .take_while(|&point| self.contains(point))
+            .map(|point| (point, &self[point]))
+    }
+
+    /// The diagonals running down and to the right, from the bottom-left corner to the
+    /// top-right corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let left = (0..self.height).rev().map(|y| Point::new(0, y));
+        let top = (1..self.width).map(|x| Point::new(x, 0));
+
+        left.chain(top)
+            .map(|start| self.ray(start, Point::new(1, 1)))
+    }
+
+    /// The diagonals running down and to the left, from the top-left corner to the
+    /// bottom-right corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let top = (0..self.width).map(|x| Point::new(x, 0));
+        let right = (1..self.height).map(|y| Point::new(self.width - 1, y));
+
+        top.chain(right)
+            .map(|start| self.ray(start, Point::new(-1, 1)))
+    }
+
+    /// A view of the rectangle between two corners (inclusive), clipped to the grid
+    pub fn view(&self, min: Point, max: Point) -> SubGrid<'_, T> {
+        let min = Point::new(min.x.max(0), min.y.max(0));
+        let max = Point::new(max.x.min(self.width - 1), max.y.min(self.height - 1));
+
+        SubGrid {
+            grid: self,
+            min,
+            max,
+        }
+    }
+
+    /// Maps a point outside the grid back inside, as if the grid was repeated infinitely
+    /// in every direction
+    pub fn wrap(&self, point: Point) -> Point {
+        point.rem_euclid(Point::new(self.width, self.height))
+    }
+
+    /// A view of the grid repeated infinitely in every direction, see [`Grid::wrap`]
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { grid: self }
+    }
+}
+
+/// Transformations, creating a new grid
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, turning rows into columns
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid 90° clockwise
+    pub fn rotate_cw(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, self.height - 1 - p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid 90° counter-clockwise
+    pub fn rotate_ccw(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(self.width - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right
+    pub fn flip_horizontal(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point::new(self.width - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point::new(p.x, self.height - 1 - p.y)].clone()
+        })
+    }
+}
+
+impl<T: Copy + PartialEq> Grid<T> {
+    /// The cardinal neighbours inside the grid
+    pub fn neighbours(&self, point: Point) -> Neighbours<'_, 'static, T> {
+        self.neighbours_with(point, Neighbourhood::Cardinal, Boundary::Clip)
+    }
+
+    /// The neighbours of a point and their value, for any neighbourhood and boundary policy
+    pub fn neighbours_with<'n>(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood<'n>,
+        boundary: Boundary,
+    ) -> Neighbours<'_, 'n, T> {
+        Neighbours {
+            grid: self,
+            point,
+            offsets: neighbourhood.offsets().iter(),
+            boundary,
+        }
     }
 
     /**
      * Returns the coordinate of the first match
      */
     pub fn find(&self, needle: T) -> Option<Point> {
+        self.find_all(needle).next()
+    }
+
+    /**
+     * Returns the coordinates of all matches, row by row
+     */
+    pub fn find_all(&self, needle: T) -> impl Iterator<Item = Point> + '_ {
         self.data
             .iter()
-            .position(|&d| d == needle)
-            .map(|index| Point::new(index as i64 % self.width, index as i64 / self.width))
+            .enumerate()
+            .filter(move |(_, &d)| d == needle)
+            .map(|(index, _)| self.point_of(index))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    // points outside the grid will panic
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.data[self.checked_index(point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let index = self.checked_index(point);
+        &mut self.data[index]
+    }
+}
+
+/// A rectangular part of a grid. Points are in the coordinates of the underlying grid
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    /// top-left corner
+    pub min: Point,
+    /// bottom-right corner, inclusive
+    pub max: Point,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn width(&self) -> i64 {
+        (self.max.x - self.min.x + 1).max(0)
+    }
+
+    pub fn height(&self) -> i64 {
+        (self.max.y - self.min.y + 1).max(0)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        match self.contains(point) {
+            true => self.grid.get(point),
+            false => None,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        let grid = self.grid;
+
+        (self.min.y..=self.max.y)
+            .filter(move |_| self.width() > 0)
+            .map(move |y| {
+                let start = (y * grid.width + self.min.x) as usize;
+                &grid.data[start..start + self.width() as usize]
+            })
+    }
+
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        let grid = self.grid;
+
+        (self.min.y..=self.max.y).flat_map(move |y| {
+            (self.min.x..=self.max.x).map(move |x| {
+                let point = Point::new(x, y);
+                (point, &grid[point])
+            })
+        })
+    }
+
+    /// Copies the view into a new grid, with its top-left corner at the origin
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width(),
+            height: self.height(),
+            data: self.iter_points().map(|(_, value)| value.clone()).collect(),
+        }
     }
 }
 
-impl<T: Copy + PartialEq> Index<Point> for Grid<T> {
+impl<'a, T> Index<Point> for SubGrid<'a, T> {
     type Output = T;
 
-    // points outside the grid will throw an index out of bounds error
+    // points outside the view will panic, even if they are inside the grid
     fn index(&self, point: Point) -> &Self::Output {
-        &self.data[(self.width * point.y + point.x) as usize]
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "Point {point} is outside the view {}..{}",
+                self.min, self.max
+            )
+        })
+    }
+}
+
+/// A grid repeated infinitely in every direction. Every point is valid, which allows
+/// searching an infinite grid directly
+pub struct Tiled<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Tiled<'a, T> {
+    /// The tile a point is in, where the tile at the origin is the grid itself
+    pub fn tile(&self, point: Point) -> Point {
+        point.div_euclid(Point::new(self.grid.width, self.grid.height))
+    }
+
+    pub fn get(&self, point: Point) -> &'a T {
+        &self.grid[self.grid.wrap(point)]
+    }
+}
+
+impl<'a, T> Index<Point> for Tiled<'a, T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+    }
+}
+
+fn fmt_rows<T>(
+    grid: &Grid<T>,
+    f: &mut fmt::Formatter<'_>,
+    cell: impl Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    for (y, row) in grid.rows().enumerate() {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for value in row {
+            cell(value, f)?;
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_rows(self, f, |c, f| write!(f, "{c}"))
+    }
+}
+
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_rows(self, f, |&b, f| write!(f, "{}", b as char))
+    }
+}
+
+impl fmt::Display for Grid<i64> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_rows(self, f, |d, f| write!(f, "{d}"))
+    }
+}
+
+impl<T: fmt::Display> Grid<T> {
+    /// Starts rendering the grid with overlays, see [`Render`]
+    pub fn render(&self) -> Render<'_, T> {
+        Render {
+            grid: self,
+            overlay: HashMap::new(),
+            window: None,
+            colors: true,
+        }
+    }
+}
+
+/// Renders a grid with highlighted cells, e.g. to debug a path through the grid with
+/// `grid.render().path(path, RED).crop(min, max)`. Implements `Display`, so the result can
+/// be printed or turned into a string.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    /// replacement glyph (if any) and color per point. Later overlays replace earlier ones
+    overlay: HashMap<Point, (Option<char>, &'static str)>,
+    window: Option<(Point, Point)>,
+    colors: bool,
+}
+
+impl<'a, T: fmt::Display> Render<'a, T> {
+    /// Colors the cells at the points, keeping their values
+    pub fn highlight(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        color: &'static str,
+    ) -> Self {
+        for point in points {
+            self.overlay.insert(point, (None, color));
+        }
+        self
+    }
+
+    /// Replaces the cells at the points by a colored glyph
+    pub fn mark(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        glyph: char,
+        color: &'static str,
+    ) -> Self {
+        for point in points {
+            self.overlay.insert(point, (Some(glyph), color));
+        }
+        self
+    }
+
+    /// Draws a path as arrows pointing towards the next point. The last point is highlighted
+    pub fn path(mut self, points: impl IntoIterator<Item = Point>, color: &'static str) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+
+        for pair in points.windows(2) {
+            let glyph = match (
+                (pair[1].x - pair[0].x).signum(),
+                (pair[1].y - pair[0].y).signum(),
+            ) {
+                (1, 0) => '>',
+                (-1, 0) => '<',
+                (0, 1) => 'v',
+                (0, -1) => '^',
+                _ => '*',
+            };
+            self.overlay.insert(pair[0], (Some(glyph), color));
+        }
+
+        if let Some(&last) = points.last() {
+            self.overlay.insert(last, (None, color));
+        }
+        self
+    }
+
+    /// Only renders the rectangle between two corners (inclusive)
+    pub fn crop(mut self, min: Point, max: Point) -> Self {
+        self.window = Some((min, max));
+        self
+    }
+
+    /// Leaves out the ansi color codes, e.g. for comparing the output in tests
+    pub fn plain(mut self) -> Self {
+        self.colors = false;
+        self
+    }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for Render<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.window.unwrap_or((
+            ORIGIN,
+            Point::new(self.grid.width - 1, self.grid.height - 1),
+        ));
+        let view = self.grid.view(min, max);
+
+        for y in view.min.y..=view.max.y {
+            if y > view.min.y {
+                writeln!(f)?;
+            }
+
+            for x in view.min.x..=view.max.x {
+                let point = Point::new(x, y);
+
+                let (glyph, color) = match self.overlay.get(&point) {
+                    Some(&overlay) => overlay,
+                    None => (None, ""),
+                };
+                let (color, reset) = match self.colors && !color.is_empty() {
+                    true => (color, RESET),
+                    false => ("", ""),
+                };
+
+                match glyph {
+                    Some(glyph) => write!(f, "{color}{glyph}{reset}")?,
+                    None => write!(f, "{color}{}{reset}", self.grid[point])?,
+                }
+            }
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{grid::Grid, point::Point};
+    use crate::{
+        ansi::{BLUE, RED, RESET},
+        grid::{Grid, GridError},
+        point::Point,
+    };
 
     const INPUT: &str = r"12345
 67891
@@ -104,7 +677,7 @@ mod test {
 
     #[test]
     fn index_test() {
-        let grid = Grid::parse(INPUT);
+        let grid = Grid::parse(INPUT).unwrap();
         assert_eq!(1, grid[Point::new(0, 0)]);
         assert_eq!(4, grid[Point::new(3, 0)]);
         assert_eq!(7, grid[Point::new(0, 3)]);
@@ -112,7 +685,7 @@ mod test {
 
     #[test]
     fn contains_test() {
-        let grid = Grid::parse(INPUT);
+        let grid = Grid::parse(INPUT).unwrap();
 
         assert!(grid.contains(Point::new(0, 0)));
         assert!(grid.contains(Point::new(3, 2)));
@@ -120,4 +693,245 @@ mod test {
         assert!(!grid.contains(Point::new(-1, 0)));
         assert!(!grid.contains(Point::new(5, 5)));
     }
+
+    #[test]
+    fn parse_with_test() {
+        let grid = Grid::parse_with("#.\n.#\n", |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected '#' or '.'"),
+        })
+        .unwrap();
+
+        assert_eq!(2, grid.width);
+        assert_eq!(2, grid.height);
+        assert_eq!(vec![true, false, false, true], grid.data);
+    }
+
+    #[test]
+    fn parse_bytes_test() {
+        let grid = Grid::parse_bytes("ab\r\ncd\r\n").unwrap();
+        assert_eq!(2, grid.width);
+        assert_eq!(2, grid.height);
+        assert_eq!(b'c', grid[Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert_eq!(Some(GridError::Empty), Grid::parse("").err());
+        assert_eq!(Some(GridError::Empty), Grid::parse_bytes("\n\n").err());
+        assert_eq!(
+            Some(GridError::Ragged {
+                row: 2,
+                expected: 3,
+                found: 2
+            }),
+            Grid::parse_char("abc\ndef\ngh").err()
+        );
+        assert_eq!(
+            Some(GridError::Unmappable {
+                row: 1,
+                column: 2,
+                found: 'x',
+                reason: "not a digit".to_string()
+            }),
+            Grid::parse("123\n45x").err()
+        );
+    }
+
+    #[test]
+    fn index_mut_test() {
+        let mut grid = Grid::new(3, 2, '.');
+        grid[Point::new(2, 1)] = '#';
+
+        assert_eq!('#', grid[Point::new(2, 1)]);
+        assert_eq!(vec!['.', '.', '.', '.', '.', '#'], grid.data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_outside_test() {
+        // would wrap around to the next row without the bounds check
+        let grid = Grid::new(3, 2, 0);
+        let _ = grid[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn get_test() {
+        let mut grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(Some(&9), grid.get(Point::new(3, 1)));
+        assert_eq!(None, grid.get(Point::new(5, 0)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+
+        *grid.get_mut(Point::new(3, 1)).unwrap() = 0;
+        assert_eq!(0, grid[Point::new(3, 1)]);
+        assert_eq!(None, grid.get_mut(Point::new(5, 5)));
+    }
+
+    #[test]
+    fn map_fill_swap_test() {
+        let mut grid = Grid::parse(INPUT).unwrap().map(|&d| d % 2 == 0);
+        assert!(!grid[Point::new(0, 0)]);
+        assert!(grid[Point::new(1, 0)]);
+
+        grid.swap(Point::new(0, 0), Point::new(1, 0));
+        assert!(grid[Point::new(0, 0)]);
+        assert!(!grid[Point::new(1, 0)]);
+
+        grid.fill(true);
+        assert!(grid.data.iter().all(|&b| b));
+    }
+
+    #[test]
+    fn rows_columns_test() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        let rows = grid.rows().collect::<Vec<_>>();
+        assert_eq!(5, rows.len());
+        assert_eq!(&[6, 7, 8, 9, 1], rows[1]);
+
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(5, columns.len());
+        assert_eq!(vec![2, 7, 3, 8, 4], columns[1]);
+    }
+
+    #[test]
+    fn iter_points_test() {
+        let grid = Grid::parse_char("ab\ncd").unwrap();
+        assert_eq!(
+            vec![
+                (Point::new(0, 0), &'a'),
+                (Point::new(1, 0), &'b'),
+                (Point::new(0, 1), &'c'),
+                (Point::new(1, 1), &'d'),
+            ],
+            grid.iter_points().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn find_all_test() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(Some(Point::new(0, 0)), grid.find(1));
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(4, 1), Point::new(3, 3)],
+            grid.find_all(1).collect::<Vec<_>>()
+        );
+        assert_eq!(None, grid.find_all(0).next());
+    }
+
+    #[test]
+    fn diagonals_test() {
+        let grid = Grid::parse_char("abc\ndef").unwrap();
+        let collect = |diagonals: Vec<Vec<(Point, &char)>>| {
+            diagonals
+                .iter()
+                .map(|d| d.iter().map(|(_, &c)| c).collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec!["d", "ae", "bf", "c"],
+            collect(grid.diagonals().map(|d| d.collect()).collect())
+        );
+        assert_eq!(
+            vec!["a", "bd", "ce", "f"],
+            collect(grid.anti_diagonals().map(|d| d.collect()).collect())
+        );
+    }
+
+    #[test]
+    fn view_test() {
+        let grid = Grid::parse(INPUT).unwrap();
+        let view = grid.view(Point::new(3, -1), Point::new(7, 1));
+
+        assert_eq!(2, view.width());
+        assert_eq!(2, view.height());
+        assert_eq!(
+            vec![&[4, 5][..], &[9, 1][..]],
+            view.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(9, view[Point::new(3, 1)]);
+        assert_eq!(None, view.get(Point::new(2, 1)));
+
+        let copy = view.to_grid();
+        assert_eq!(vec![4, 5, 9, 1], copy.data);
+        assert_eq!(9, copy[Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn transform_test() {
+        // ab
+        // cd
+        // ef
+        let grid = Grid::parse_char("ab\ncd\nef").unwrap();
+        let text = |grid: Grid<char>| {
+            grid.rows()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["ace", "bdf"], text(grid.transpose()));
+        assert_eq!(vec!["eca", "fdb"], text(grid.rotate_cw()));
+        assert_eq!(vec!["bdf", "ace"], text(grid.rotate_ccw()));
+        assert_eq!(vec!["ba", "dc", "fe"], text(grid.flip_horizontal()));
+        assert_eq!(vec!["ef", "cd", "ab"], text(grid.flip_vertical()));
+
+        let rotated = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(grid.data, rotated.data);
+    }
+
+    #[test]
+    fn tiled_test() {
+        let grid = Grid::parse(INPUT).unwrap();
+        let tiled = grid.tiled();
+
+        assert_eq!(Point::new(0, 0), grid.wrap(Point::new(5, -5)));
+        assert_eq!(Point::new(4, 1), grid.wrap(Point::new(-1, 11)));
+
+        assert_eq!(grid[Point::new(4, 1)], tiled[Point::new(-1, 11)]);
+        assert_eq!(Point::new(-1, 2), tiled.tile(Point::new(-1, 11)));
+        assert_eq!(Point::new(0, 0), tiled.tile(Point::new(4, 4)));
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(INPUT, Grid::parse(INPUT).unwrap().to_string());
+        assert_eq!("#.\n.#", Grid::parse_char("#.\n.#\n").unwrap().to_string());
+        assert_eq!("#.\n.#", Grid::parse_bytes("#.\n.#").unwrap().to_string());
+    }
+
+    #[test]
+    fn render_test() {
+        let grid = Grid::parse_char("....\n....\n....").unwrap();
+        let path = [
+            Point::new(0, 0),
+            Point::new(2, 0),
+            Point::new(2, 1),
+            Point::new(1, 1),
+        ];
+
+        let rendered = grid
+            .render()
+            .path(path, RED)
+            .mark([Point::new(3, 2)], '#', BLUE)
+            .plain()
+            .to_string();
+        assert_eq!(">.v.\n..<.\n...#", rendered);
+
+        let cropped = grid
+            .render()
+            .mark([Point::new(3, 2)], '#', BLUE)
+            .crop(Point::new(2, 1), Point::new(10, 10))
+            .plain()
+            .to_string();
+        assert_eq!("..\n.#", cropped);
+
+        let colored = grid.render().highlight([Point::new(0, 0)], RED).to_string();
+        assert!(colored.starts_with(&format!("{RED}.{RESET}...\n")));
+    }
 }
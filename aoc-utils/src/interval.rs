@@ -0,0 +1,404 @@
+//! Inclusive integer intervals, sets of disjoint intervals and N-dimensional cuboids, for
+//! puzzles that split ranges of values instead of enumerating them.
+
+use core::fmt;
+
+/// Integers that have a next and previous value, which inclusive intervals need to split
+pub trait Discrete: Copy + Ord {
+    fn succ(self) -> Self;
+    fn pred(self) -> Self;
+    /// The number of values between two bounds (both inclusive)
+    ///
+    /// # Panics
+    ///
+    /// If the bounds span every `u128`, as there is one value too many to count
+    fn count(start: Self, end: Self) -> u128;
+}
+
+/// Automatically generate discrete implementations for integers
+macro_rules! discrete {
+    ($($t:ty)*) => ($(
+        impl Discrete for $t {
+            fn succ(self) -> Self {
+                self + 1
+            }
+
+            fn pred(self) -> Self {
+                self - 1
+            }
+
+            fn count(start: Self, end: Self) -> u128 {
+                (end.abs_diff(start) as u128)
+                    .checked_add(1)
+                    .expect("Too many values to count")
+            }
+        }
+    )*)
+}
+
+discrete!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64);
+
+/// The values from `start` up to and including `end`, which is never empty. Lengths are
+/// counted in `u128`, which holds the length of every interval except the full `u128` range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Discrete> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "An interval cannot end before it starts");
+        Interval { start, end }
+    }
+
+    /// An interval, unless it would end before it starts
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    #[allow(clippy::len_without_is_empty)] // an interval always holds at least one value
+    pub fn len(&self) -> u128 {
+        T::count(self.start, self.end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether the other interval lies completely within this one
+    pub fn covers(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        // see https://stackoverflow.com/a/3269471
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Interval::try_new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The interval covering both, if they overlap or are adjacent
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let (first, second) = match self.start <= other.start {
+            true => (self, other),
+            false => (other, self),
+        };
+
+        // stepping back from the later start cannot overflow, unlike stepping past the end
+        (second.start <= first.end || second.start.pred() == first.end)
+            .then(|| Interval::new(first.start, first.end.max(second.end)))
+    }
+
+    /// The parts of this interval before and after the other one
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if !self.intersects(other) {
+            return match self.end < other.start {
+                true => (Some(*self), None),
+                false => (None, Some(*self)),
+            };
+        }
+
+        let before =
+            (self.start < other.start).then(|| Interval::new(self.start, other.start.pred()));
+        let after = (other.end < self.end).then(|| Interval::new(other.end.succ(), self.end));
+        (before, after)
+    }
+
+    /// Splits into the values below `at` and the values from `at` onwards
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        if at <= self.start {
+            (None, Some(*self))
+        } else if at > self.end {
+            (Some(*self), None)
+        } else {
+            (
+                Some(Interval::new(self.start, at.pred())),
+                Some(Interval::new(at, self.end)),
+            )
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// A set of values stored as sorted, disjoint and non-adjacent intervals
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub fn insert(&mut self, mut interval: Interval<T>) {
+        // absorb every interval overlapping or adjacent to the new one
+        let mut kept = Vec::with_capacity(self.intervals.len() + 1);
+        for existing in self.intervals.drain(..) {
+            match existing.union(&interval) {
+                Some(union) => interval = union,
+                None => kept.push(existing),
+            }
+        }
+
+        let index = kept.partition_point(|existing| existing.start < interval.start);
+        kept.insert(index, interval);
+        self.intervals = kept;
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|existing| {
+                let (before, after) = existing.difference(&interval);
+                before.into_iter().chain(after)
+            })
+            .collect();
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    /// The number of values in the set
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The disjoint intervals, in ascending order
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+}
+
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/// An N-dimensional box, with an interval along every axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Discrete, const N: usize> Cuboid<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Cuboid { axes }
+    }
+
+    /// The number of points inside the cuboid
+    ///
+    /// # Panics
+    ///
+    /// If the volume does not fit in `u128`, e.g. for two axes spanning every `u64`
+    pub fn volume(&self) -> u128 {
+        self.axes
+            .iter()
+            .try_fold(1u128, |volume, axis| volume.checked_mul(axis.len()))
+            .expect("Too many points to count")
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, p)| axis.contains(p))
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.axes
+            .iter()
+            .zip(&other.axes)
+            .all(|(a, b)| a.intersects(b))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersection(other)?;
+        }
+        Some(Cuboid { axes })
+    }
+
+    /// Splits along an axis into the part below `at` and the part from `at` onwards
+    pub fn split_at(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(at);
+        let with = |interval: Interval<T>| {
+            let mut cuboid = *self;
+            cuboid.axes[axis] = interval;
+            cuboid
+        };
+        (below.map(with), above.map(with))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interval::{Cuboid, Interval, IntervalSet};
+
+    #[test]
+    fn interval_test() {
+        let a = Interval::new(1, 10);
+        let b = Interval::new(5, 15);
+
+        assert_eq!(10, a.len());
+        assert!(a.contains(10));
+        assert!(!a.contains(11));
+        assert!(a.covers(&Interval::new(2, 3)));
+        assert!(!a.covers(&b));
+
+        assert_eq!(Some(Interval::new(5, 10)), a.intersection(&b));
+        assert_eq!(None, a.intersection(&Interval::new(11, 12)));
+        assert_eq!(None, Interval::try_new(2, 1));
+        assert_eq!("1..=10", a.to_string());
+    }
+
+    #[test]
+    fn full_width_len_test() {
+        assert_eq!(1 << 64, Interval::new(i64::MIN, i64::MAX).len());
+        assert_eq!(1 << 64, Interval::new(0, u64::MAX).len());
+        assert_eq!(u128::MAX, Interval::new(1, u128::MAX).len());
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many values to count")]
+    fn full_u128_len_test() {
+        Interval::new(0, u128::MAX).len();
+    }
+
+    #[test]
+    fn union_test() {
+        let a = Interval::new(1, 10);
+
+        assert_eq!(Some(Interval::new(1, 15)), a.union(&Interval::new(5, 15)));
+        assert_eq!(Some(Interval::new(-3, 10)), a.union(&Interval::new(-3, 0)));
+        assert_eq!(None, a.union(&Interval::new(12, 15)));
+    }
+
+    #[test]
+    fn difference_test() {
+        let a = Interval::new(1, 10);
+
+        assert_eq!(
+            (Some(Interval::new(1, 3)), Some(Interval::new(8, 10))),
+            a.difference(&Interval::new(4, 7))
+        );
+        assert_eq!(
+            (None, Some(Interval::new(6, 10))),
+            a.difference(&Interval::new(0, 5))
+        );
+        assert_eq!((None, None), a.difference(&Interval::new(0, 20)));
+        assert_eq!((Some(a), None), a.difference(&Interval::new(20, 30)));
+    }
+
+    #[test]
+    fn split_at_test() {
+        let a = Interval::new(1u32, 4000);
+
+        assert_eq!(
+            (
+                Some(Interval::new(1, 1999)),
+                Some(Interval::new(2000, 4000))
+            ),
+            a.split_at(2000)
+        );
+        assert_eq!((None, Some(a)), a.split_at(1));
+        assert_eq!((Some(a), None), a.split_at(4001));
+    }
+
+    #[test]
+    fn interval_set_test() {
+        let mut set: IntervalSet<i32> = [
+            Interval::new(10, 20),
+            Interval::new(1, 3),
+            Interval::new(4, 5),
+            Interval::new(15, 25),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            &[Interval::new(1, 5), Interval::new(10, 25)],
+            set.intervals()
+        );
+        assert_eq!(21, set.len());
+        assert!(set.contains(12));
+        assert!(!set.contains(7));
+
+        set.remove(Interval::new(3, 12));
+        assert_eq!(
+            &[Interval::new(1, 2), Interval::new(13, 25)],
+            set.intervals()
+        );
+
+        set.insert(Interval::new(3, 12));
+        assert_eq!(&[Interval::new(1, 25)], set.intervals());
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many points to count")]
+    fn full_volume_test() {
+        Cuboid::new([Interval::new(0u64, u64::MAX); 2]).volume();
+    }
+
+    #[test]
+    fn cuboid_test() {
+        let a = Cuboid::new([
+            Interval::new(0, 9),
+            Interval::new(0, 9),
+            Interval::new(0, 1),
+        ]);
+        let b = Cuboid::new([
+            Interval::new(5, 14),
+            Interval::new(8, 9),
+            Interval::new(1, 1),
+        ]);
+
+        assert_eq!(200, a.volume());
+        assert_eq!(
+            1 << 64,
+            Cuboid::new([Interval::new(0u64, u64::MAX)]).volume()
+        );
+        assert!(a.contains([9, 0, 1]));
+        assert!(!a.contains([9, 0, 2]));
+        assert!(a.intersects(&b));
+        assert_eq!(
+            Some(Cuboid::new([
+                Interval::new(5, 9),
+                Interval::new(8, 9),
+                Interval::new(1, 1)
+            ])),
+            a.intersection(&b)
+        );
+
+        let (below, above) = a.split_at(1, 3);
+        assert_eq!(60, below.unwrap().volume());
+        assert_eq!(140, above.unwrap().volume());
+    }
+}
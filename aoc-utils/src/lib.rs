@@ -1,6 +1,15 @@
+pub mod alloc;
 pub mod ansi;
 pub mod direction;
+pub mod geometry;
 pub mod grid;
+pub mod interval;
+pub mod neighbourhood;
 pub mod num;
 pub mod parser;
 pub mod point;
+pub mod point3;
+pub mod pointn;
+pub mod search;
+pub mod sparse;
+pub mod visualize;
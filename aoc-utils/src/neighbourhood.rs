@@ -0,0 +1,168 @@
+//! Neighbourhoods and boundary policies for [`Grid::neighbours_with`](crate::grid::Grid::neighbours_with).
+
+use std::slice;
+
+use crate::{
+    grid::Grid,
+    point::{Point, DOWN, LEFT, RIGHT, UP},
+};
+
+pub const CARDINAL: [Point; 4] = [UP, RIGHT, DOWN, LEFT];
+
+/// The cardinal offsets followed by the diagonal ones, in the same order as [`Point::neighbours`]
+pub const MOORE: [Point; 8] = [
+    UP,
+    RIGHT,
+    DOWN,
+    LEFT,
+    Point::new(-1, -1), // north-west
+    Point::new(1, -1),  // north-east
+    Point::new(-1, 1),  // south-west
+    Point::new(1, 1),   // south-east
+];
+
+pub const KNIGHT: [Point; 8] = [
+    Point::new(1, -2),
+    Point::new(2, -1),
+    Point::new(2, 1),
+    Point::new(1, 2),
+    Point::new(-1, 2),
+    Point::new(-2, 1),
+    Point::new(-2, -1),
+    Point::new(-1, -2),
+];
+
+/// The offsets considered adjacent to a point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood<'a> {
+    /// up, right, down and left
+    Cardinal,
+    /// cardinal and diagonal
+    Moore,
+    /// the moves of a knight in chess
+    Knight,
+    Custom(&'a [Point]),
+}
+
+impl<'a> Neighbourhood<'a> {
+    pub fn offsets(self) -> &'a [Point] {
+        match self {
+            Neighbourhood::Cardinal => &CARDINAL,
+            Neighbourhood::Moore => &MOORE,
+            Neighbourhood::Knight => &KNIGHT,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// How neighbours outside the grid are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// skipped
+    Clip,
+    /// mapped back inside the grid, see [`Grid::wrap`]
+    Wrap,
+    /// kept as is, with the value of the grid repeated infinitely, see [`Grid::tiled`]
+    Infinite,
+}
+
+/// The neighbours of a point along with their value, see [`Grid::neighbours_with`]
+pub struct Neighbours<'g, 'n, T> {
+    pub(crate) grid: &'g Grid<T>,
+    pub(crate) point: Point,
+    pub(crate) offsets: slice::Iter<'n, Point>,
+    pub(crate) boundary: Boundary,
+}
+
+impl<'g, 'n, T: Copy> Iterator for Neighbours<'g, 'n, T> {
+    type Item = (Point, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let neighbour = self.point + *self.offsets.next()?;
+
+            match self.boundary {
+                Boundary::Clip if !self.grid.contains(neighbour) => continue,
+                Boundary::Clip => return Some((neighbour, self.grid[neighbour])),
+                Boundary::Wrap => {
+                    let wrapped = self.grid.wrap(neighbour);
+                    return Some((wrapped, self.grid[wrapped]));
+                }
+                Boundary::Infinite => {
+                    return Some((neighbour, self.grid[self.grid.wrap(neighbour)]))
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.boundary {
+            Boundary::Clip => (0, Some(self.offsets.len())),
+            _ => (self.offsets.len(), Some(self.offsets.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grid::Grid,
+        neighbourhood::{Boundary, Neighbourhood},
+        point::Point,
+    };
+
+    #[test]
+    fn neighbourhood_test() {
+        let grid = Grid::from_fn(5, 5, |p| p.y * 5 + p.x);
+        let centre = Point::new(2, 2);
+
+        let count = |neighbourhood| {
+            grid.neighbours_with(centre, neighbourhood, Boundary::Clip)
+                .count()
+        };
+        assert_eq!(4, count(Neighbourhood::Cardinal));
+        assert_eq!(8, count(Neighbourhood::Moore));
+        assert_eq!(8, count(Neighbourhood::Knight));
+
+        let offsets = [Point::new(0, 2), Point::new(3, 0)];
+        assert_eq!(
+            vec![(Point::new(2, 4), 22)],
+            grid.neighbours_with(centre, Neighbourhood::Custom(&offsets), Boundary::Clip)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn boundary_test() {
+        let grid = Grid::from_fn(3, 3, |p| p.y * 3 + p.x);
+        let corner = Point::new(0, 0);
+
+        let neighbours = |boundary| {
+            grid.neighbours_with(corner, Neighbourhood::Cardinal, boundary)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![(Point::new(1, 0), 1), (Point::new(0, 1), 3)],
+            neighbours(Boundary::Clip)
+        );
+        assert_eq!(
+            vec![
+                (Point::new(0, 2), 6),
+                (Point::new(1, 0), 1),
+                (Point::new(0, 1), 3),
+                (Point::new(2, 0), 2)
+            ],
+            neighbours(Boundary::Wrap)
+        );
+        assert_eq!(
+            vec![
+                (Point::new(0, -1), 6),
+                (Point::new(1, 0), 1),
+                (Point::new(0, 1), 3),
+                (Point::new(-1, 0), 2)
+            ],
+            neighbours(Boundary::Infinite)
+        );
+    }
+}
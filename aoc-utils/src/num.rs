@@ -1,7 +1,71 @@
-pub trait Integer: Sized + PartialOrd + Ord + Eq {
+pub trait Integer: Sized + PartialOrd + Ord + Eq + Copy {
     fn gcd_binary(self, other: Self) -> Self;
     fn gcd(self, other: &Self) -> Self;
     fn lcm(self, other: &Self) -> Self;
+
+    /// The least common multiple, or `None` if it does not fit in the type
+    fn checked_lcm(self, other: &Self) -> Option<Self>;
+
+    /// The gcd along with the Bézout coefficients `x` and `y`, such that
+    /// `self * x + other * y == gcd`
+    ///
+    /// # Panics
+    ///
+    /// If either value does not fit in `i128`, which only happens for large `u128` values
+    fn extended_gcd(self, other: &Self) -> (Self, i128, i128);
+
+    /// The `x` for which `self * x ≡ 1 (mod modulus)`, which only exists when `self` and
+    /// `modulus` are coprime
+    ///
+    /// # Panics
+    ///
+    /// Like [`Integer::extended_gcd`], if either value does not fit in `i128`
+    fn mod_inverse(self, modulus: &Self) -> Option<Self>;
+
+    /// `self^exp mod modulus`, computed in 128 bits so the intermediate products never overflow
+    ///
+    /// # Panics
+    ///
+    /// If the modulus is not positive or does not fit in 64 bits
+    fn mod_pow(self, exp: u64, modulus: &Self) -> Self;
+
+    /// Solves `x ≡ a (mod m)` and `x ≡ b (mod n)`, returning `x` and the modulus of the
+    /// solution `lcm(m, n)`. The moduli do not need to be coprime, but then a solution only
+    /// exists if `a ≡ b (mod gcd(m, n))`. Returns `None` for a zero modulus, or if any value
+    /// does not fit in `i128`
+    fn crt(a: Self, m: Self, b: Self, n: Self) -> Option<(Self, Self)>;
+
+    /// The largest integer whose square is at most `self`
+    ///
+    /// # Panics
+    ///
+    /// If `self` is negative
+    fn integer_sqrt(self) -> Self;
+
+    /// The prime factors of the magnitude with their multiplicity, in ascending order. The
+    /// sign is ignored, so `-12` factors like `12`, and `0` and `±1` have no prime factors
+    fn factorize(self) -> Vec<(Self, u32)>;
+}
+
+/// The extended euclidean algorithm, the intermediate values fit for every integer type
+/// except for `u128` values beyond `i128::MAX`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    // keep the gcd positive for negative inputs
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
 }
 
 /// Automatically generate gcd for integer
@@ -51,15 +115,150 @@ macro_rules! integer {
                 }
                 self * (*other / self.gcd(other))
             }
+
+            fn checked_lcm(self, other: &Self) -> Option<Self> {
+                if self == 0 && *other == 0 {
+                    return Some(0)
+                }
+                self.checked_mul(*other / self.gcd(other))
+            }
+
+            fn extended_gcd(self, other: &Self) -> (Self, i128, i128) {
+                let fits = |value: Self| {
+                    i128::try_from(value).expect("extended_gcd needs values that fit in i128")
+                };
+                let (gcd, x, y) = extended_gcd(fits(self), fits(*other));
+                (gcd as Self, x, y)
+            }
+
+            fn mod_inverse(self, modulus: &Self) -> Option<Self> {
+                if *modulus < 1 {
+                    return None;
+                }
+                let (gcd, x, _) = self.extended_gcd(modulus);
+                (gcd == 1).then(|| x.rem_euclid(*modulus as i128) as Self)
+            }
+
+            fn mod_pow(self, mut exp: u64, modulus: &Self) -> Self {
+                assert!(*modulus > 0, "The modulus must be positive");
+                // the remainder fits in i128 even when self does not
+                let remainder = (self % *modulus) as i128;
+                let modulus = u64::try_from(*modulus).expect("The modulus must fit in 64 bits");
+                let modulus = modulus as u128;
+                let mut base = remainder.rem_euclid(modulus as i128) as u128;
+                let mut result = 1 % modulus;
+
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = result * base % modulus;
+                    }
+                    base = base * base % modulus;
+                    exp >>= 1;
+                }
+                result as Self
+            }
+
+            fn crt(a: Self, m: Self, b: Self, n: Self) -> Option<(Self, Self)> {
+                let [a, m, b, n] = [a, m, b, n].map(|value| i128::try_from(value).ok());
+                let (a, m, b, n) = (a?, m?, b?, n?);
+                if m == 0 || n == 0 {
+                    return None;
+                }
+
+                let (gcd, p, _) = extended_gcd(m, n);
+                let diff = b.checked_sub(a)?;
+
+                if diff % gcd != 0 {
+                    return None;
+                }
+
+                let lcm = m.checked_mul(n / gcd)?;
+                let k = (diff / gcd).checked_mul(p)?.rem_euclid(n / gcd);
+                let x = a.checked_add(m.checked_mul(k)?)?.rem_euclid(lcm);
+
+                Some((Self::try_from(x).ok()?, Self::try_from(lcm).ok()?))
+            }
+
+            #[allow(unused_comparisons)] // always true for unsigned types
+            fn integer_sqrt(self) -> Self {
+                assert!(self >= 0, "Cannot take the square root of a negative number");
+                self.isqrt()
+            }
+
+            fn factorize(self) -> Vec<(Self, u32)> {
+                let mut factors = vec![];
+                if self == 0 {
+                    return factors;
+                }
+
+                // dividing out the twos first leaves an odd number, whose magnitude fits in
+                // the type even when starting from MIN
+                let twos = self.trailing_zeros();
+                if twos > 0 {
+                    factors.push((2, twos));
+                }
+                let mut n = (self >> twos).abs_diff(0) as Self;
+                let mut divisor: Self = 3;
+
+                while divisor <= n / divisor {
+                    let mut count = 0;
+                    while n % divisor == 0 {
+                        n /= divisor;
+                        count += 1;
+                    }
+                    if count > 0 {
+                        factors.push((divisor, count));
+                    }
+                    divisor += 2;
+                }
+
+                if n > 1 {
+                    factors.push((n, 1));
+                }
+                factors
+            }
         }
     )*)
 }
 
 integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64);
 
+/// The least common multiple of all the numbers, or `None` if there are none or the result
+/// does not fit in the type
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut numbers = numbers.into_iter();
+    let first = numbers.next()?;
+    numbers.try_fold(first, |acc, next| acc.checked_lcm(&next))
+}
+
+/// Solves a system of congruences `x ≡ a (mod m)`, given as `(a, m)` pairs, see [`Integer::crt`]
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let mut congruences = congruences.into_iter();
+    let first = congruences.next()?;
+    congruences.try_fold(first, |(a, m), (b, n)| T::crt(a, m, b, n))
+}
+
+/// All primes up to and including `limit`, using the sieve of Eratosthenes
+pub fn primes(limit: usize) -> Vec<usize> {
+    let mut composite = vec![false; limit + 1];
+    let mut primes = vec![];
+
+    for n in 2..=limit {
+        if composite[n] {
+            continue;
+        }
+        primes.push(n);
+
+        for multiple in (n * n..=limit).step_by(n) {
+            composite[multiple] = true;
+        }
+    }
+    primes
+}
+
 #[cfg(test)]
 mod test {
-    use crate::num::Integer;
+    use crate::num::{crt, lcm_all, primes, Integer};
 
     #[test]
     fn gcd_test() {
@@ -76,4 +275,110 @@ mod test {
         assert_eq!(2.lcm(&4), 4);
         assert_eq!(0.lcm(&0), 0);
     }
+
+    #[test]
+    fn checked_lcm_test() {
+        assert_eq!(Some(21), 7u8.checked_lcm(&3));
+        assert_eq!(None, 200u8.checked_lcm(&3));
+        assert_eq!(Some(0), 0u8.checked_lcm(&0));
+
+        assert_eq!(Some(60), lcm_all([4u64, 6, 10, 15]));
+        assert_eq!(None, lcm_all([u64::MAX, 2]));
+        assert_eq!(None, lcm_all::<u64>([]));
+    }
+
+    #[test]
+    fn extended_gcd_test() {
+        let (gcd, x, y) = 240u32.extended_gcd(&46);
+        assert_eq!(2, gcd);
+        assert_eq!(2, 240 * x + 46 * y);
+
+        let (gcd, x, y) = (-12i64).extended_gcd(&18);
+        assert_eq!(6, gcd);
+        assert_eq!(6, -12 * x + 18 * y);
+    }
+
+    #[test]
+    fn modular_test() {
+        assert_eq!(Some(4), 3u32.mod_inverse(&11));
+        assert_eq!(Some(7), (-3i32).mod_inverse(&11));
+        assert_eq!(None, 4u32.mod_inverse(&8));
+
+        assert_eq!(445, 4u64.mod_pow(13, &497));
+        assert_eq!(1, 7i64.mod_pow(0, &13));
+        assert_eq!(0, 7i64.mod_pow(5, &1));
+        // the intermediate products do not fit in 64 bits
+        assert_eq!(1, (u64::MAX - 1).mod_pow(2, &(u64::MAX - 2)));
+        // the base is reduced before it has to fit in 128 signed bits
+        assert_eq!(1, (u128::MAX - 1).mod_pow(2, &5));
+        assert_eq!(1, (-3i8).mod_pow(3, &7));
+        assert_eq!(None, 3i32.mod_inverse(&-11));
+        assert_eq!(None, 3u32.mod_inverse(&0));
+    }
+
+    #[test]
+    #[should_panic(expected = "The modulus must fit in 64 bits")]
+    fn mod_pow_large_modulus_test() {
+        3u128.mod_pow(2, &(u64::MAX as u128 + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "extended_gcd needs values that fit in i128")]
+    fn mod_inverse_large_modulus_test() {
+        3u128.mod_inverse(&u128::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "The modulus must be positive")]
+    fn mod_pow_negative_modulus_test() {
+        3i64.mod_pow(2, &-5);
+    }
+
+    #[test]
+    fn crt_test() {
+        assert_eq!(Some((23, 105)), crt([(2u64, 3), (3, 5), (2, 7)]));
+
+        // non-coprime moduli
+        assert_eq!(Some((10, 12)), crt([(2i64, 4), (4, 6)]));
+        assert_eq!(None, crt([(1i64, 4), (2, 6)]));
+
+        assert_eq!(None, u64::crt(1, 0, 1, 0));
+        assert_eq!(None, u64::crt(1, 3, 1, 0));
+        assert_eq!(None, u128::crt(1, u128::MAX, 2, 3));
+    }
+
+    #[test]
+    fn integer_sqrt_test() {
+        assert_eq!(0, 0u32.integer_sqrt());
+        assert_eq!(3, 15u32.integer_sqrt());
+        assert_eq!(4, 16i64.integer_sqrt());
+        assert_eq!(u32::MAX as u64, u64::MAX.integer_sqrt());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot take the square root of a negative number")]
+    fn integer_sqrt_negative_test() {
+        (-4i32).integer_sqrt();
+    }
+
+    #[test]
+    fn primes_test() {
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19], primes(20));
+        assert_eq!(Vec::<usize>::new(), primes(1));
+
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], 360u32.factorize());
+        assert_eq!(vec![(97, 1)], 97u8.factorize());
+        assert_eq!(Vec::<(u8, u32)>::new(), 1u8.factorize());
+        assert_eq!(Vec::<(u8, u32)>::new(), 0u8.factorize());
+
+        // the sign is ignored, even for MIN whose magnitude does not fit
+        assert_eq!(vec![(2, 2), (3, 1)], (-12i64).factorize());
+        assert_eq!(Vec::<(i32, u32)>::new(), (-1i32).factorize());
+        assert_eq!(vec![(2, 63)], i64::MIN.factorize());
+        assert_eq!(vec![(3, 1), (43, 1)], (-129i16).factorize());
+        assert_eq!(
+            vec![(3, 1), (5, 1), (17, 1), (257, 1)],
+            u16::MAX.factorize()
+        );
+    }
 }
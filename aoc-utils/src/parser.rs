@@ -1,68 +1,749 @@
-use std::{fs::File, io::Read, str::FromStr};
+use std::{
+    borrow::Cow,
+    env,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 pub trait ParseExt {
-    fn iter_unsigned<'a, T>(
-        &'a self,
-    ) -> Box<dyn Iterator<Item = Result<T, <T as FromStr>::Err>> + 'a>
-    where
-        T: FromStr;
+    /// Every run of digits as a number, skipping anything in between
+    ///
+    /// # Panics
+    ///
+    /// If a run of digits does not fit in `T`
+    fn unsigned<T: Digits>(&self) -> Unsigned<'_, T>;
+
+    /// Every run of digits as a number, negative when directly preceded by a `-`. Note that a
+    /// `-` used as a separator is read as a sign too, e.g. `1-2` reads as `1` and `-2`
+    ///
+    /// # Panics
+    ///
+    /// If a number does not fit in `T`
+    fn signed<T: SignedDigits>(&self) -> Signed<'_, T>;
+}
+
+impl ParseExt for str {
+    fn unsigned<T: Digits>(&self) -> Unsigned<'_, T> {
+        Numbers::new(self)
+    }
+
+    fn signed<T: SignedDigits>(&self) -> Signed<'_, T> {
+        Numbers::new(self)
+    }
+}
+
+/// Integers that can be built up from decimal digits
+pub trait Digits: Copy {
+    const ZERO: Self;
+
+    /// Appends a digit, subtracting it for negative numbers so that `MIN` can be reached.
+    /// Returns `None` on overflow
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+pub trait SignedDigits: Digits {}
+
+/// Automatically generate digit implementations for integers
+macro_rules! digits {
+    ($($t:ty)*) => ($(
+        impl Digits for $t {
+            const ZERO: Self = 0;
+
+            fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                let shifted = self.checked_mul(10)?;
+                match negative {
+                    true => shifted.checked_sub(digit as $t),
+                    false => shifted.checked_add(digit as $t),
+                }
+            }
+        }
+    )*)
+}
+
+macro_rules! signed_digits {
+    ($($t:ty)*) => ($(
+        impl SignedDigits for $t {}
+    )*)
+}
+
+digits!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+signed_digits!(i8 i16 i32 i64 i128 isize);
+
+/// The numbers in a string, read byte by byte without allocating, see [`ParseExt::unsigned`]
+/// and [`ParseExt::signed`]
+pub struct Numbers<'a, T, const SIGNED: bool> {
+    bytes: &'a [u8],
+    number: PhantomData<T>,
+}
+
+pub type Unsigned<'a, T> = Numbers<'a, T, false>;
+pub type Signed<'a, T> = Numbers<'a, T, true>;
+
+impl<'a, T: Digits, const SIGNED: bool> Numbers<'a, T, SIGNED> {
+    pub fn new(s: &'a str) -> Self {
+        Numbers {
+            bytes: s.as_bytes(),
+            number: PhantomData,
+        }
+    }
+
+    /// The next number, negative if `SIGNED` and preceded by a `-`
+    fn next_number(&mut self) -> Option<T> {
+        let start = self.bytes.iter().position(u8::is_ascii_digit)?;
+        let negative = SIGNED && start > 0 && self.bytes[start - 1] == b'-';
+
+        let mut number = T::ZERO;
+        let mut end = start;
+        while let Some(&byte) = self.bytes.get(end).filter(|b| b.is_ascii_digit()) {
+            number = number.push_digit(byte - b'0', negative).unwrap_or_else(|| {
+                let digits = self.bytes[start..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_digit());
+                panic!(
+                    "{}{} does not fit in {}",
+                    if negative { "-" } else { "" },
+                    String::from_utf8_lossy(&digits.copied().collect::<Vec<_>>()),
+                    std::any::type_name::<T>()
+                )
+            });
+            end += 1;
+        }
+
+        self.bytes = &self.bytes[end..];
+        Some(number)
+    }
+}
+
+impl<'a, T: Digits> Iterator for Numbers<'a, T, false> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_number()
+    }
+}
+
+impl<'a, T: SignedDigits> Iterator for Numbers<'a, T, true> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_number()
+    }
+}
+
+pub trait CollectArray: Iterator + Sized {
+    /// Collects exactly `N` items into an array, e.g. for destructuring. Returns `None` if
+    /// there are fewer or more items
+    fn collect_array<const N: usize>(self) -> Option<[Self::Item; N]>;
+}
+
+impl<I: Iterator> CollectArray for I {
+    fn collect_array<const N: usize>(mut self) -> Option<[Self::Item; N]> {
+        let items = [(); N].map(|_| self.next());
+
+        if items.iter().any(Option::is_none) || self.next().is_some() {
+            return None;
+        }
+        Some(items.map(Option::unwrap))
+    }
+}
+
+/// A parse that did not match, with the length of the input remaining at that point so
+/// the column can be recovered once the whole line is known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    remaining: usize,
+    expected: String,
+}
+
+/// The parsed value and the remaining input
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure>;
+
+/// Anything consuming the start of the input, most often a closure returned by one of the
+/// combinators below, e.g. `pair(word(), preceded(tag(" -> "), sep_by(word(), tag(", "))))`
+pub trait Parser<'a, T> {
+    fn parse(&mut self, input: &'a str) -> Parsed<'a, T>;
+}
+
+impl<'a, T, F: FnMut(&'a str) -> Parsed<'a, T>> Parser<'a, T> for F {
+    fn parse(&mut self, input: &'a str) -> Parsed<'a, T> {
+        self(input)
+    }
+}
+
+fn fail<T>(input: &str, expected: impl Into<String>) -> Parsed<'_, T> {
+    Err(Failure {
+        remaining: input.len(),
+        expected: expected.into(),
+    })
+}
+
+/// Matches the exact text
+pub fn tag<'a>(expected: &'static str) -> impl FnMut(&'a str) -> Parsed<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => fail(input, format!("{expected:?}")),
+    }
+}
+
+/// Matches any one of the characters
+pub fn one_of<'a>(chars: &'static str) -> impl FnMut(&'a str) -> Parsed<'a, char> {
+    move |input: &'a str| match input.chars().next() {
+        Some(c) if chars.contains(c) => Ok((c, &input[c.len_utf8()..])),
+        _ => fail(input, format!("one of {chars:?}")),
+    }
+}
+
+/// Matches a non-empty run of letters, digits and underscores
+pub fn word<'a>() -> impl FnMut(&'a str) -> Parsed<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(input.len());
+
+        match end {
+            0 => fail(input, "a word"),
+            _ => Ok((&input[..end], &input[end..])),
+        }
+    }
+}
+
+/// Matches an integer with an optional leading `-`
+pub fn number<'a, T: FromStr>() -> impl FnMut(&'a str) -> Parsed<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let end = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(input.len(), |end| end + sign);
+
+        match input[..end].parse() {
+            Ok(number) if end > sign => Ok((number, &input[end..])),
+            _ => fail(input, "a number"),
+        }
+    }
+}
+
+/// Matches zero or more items separated by `separator`
+pub fn sep_by<'a, T, S>(
+    mut item: impl Parser<'a, T>,
+    mut separator: impl Parser<'a, S>,
+) -> impl FnMut(&'a str) -> Parsed<'a, Vec<T>> {
+    move |input: &'a str| {
+        let mut items = vec![];
+        let Ok((first, mut rest)) = item.parse(input) else {
+            return Ok((items, input));
+        };
+        items.push(first);
+
+        // a separator must be followed by another item
+        while let Ok((_, after)) = separator.parse(rest) {
+            let (next, after) = item.parse(after)?;
+            items.push(next);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+pub fn pair<'a, A, B>(
+    mut first: impl Parser<'a, A>,
+    mut second: impl Parser<'a, B>,
+) -> impl FnMut(&'a str) -> Parsed<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// Matches `prefix` followed by `parser`, keeping only the value of the latter
+pub fn preceded<'a, P, T>(
+    mut prefix: impl Parser<'a, P>,
+    mut parser: impl Parser<'a, T>,
+) -> impl FnMut(&'a str) -> Parsed<'a, T> {
+    move |input: &'a str| {
+        let (_, rest) = prefix.parse(input)?;
+        parser.parse(rest)
+    }
+}
+
+/// Matches `parser` followed by `suffix`, keeping only the value of the former
+pub fn terminated<'a, T, S>(
+    mut parser: impl Parser<'a, T>,
+    mut suffix: impl Parser<'a, S>,
+) -> impl FnMut(&'a str) -> Parsed<'a, T> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        let (_, rest) = suffix.parse(rest)?;
+        Ok((value, rest))
+    }
+}
+
+/// Tries the parser, without consuming anything when it does not match
+pub fn optional<'a, T>(
+    mut parser: impl Parser<'a, T>,
+) -> impl FnMut(&'a str) -> Parsed<'a, Option<T>> {
+    move |input: &'a str| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+pub fn map<'a, T, U>(
+    mut parser: impl Parser<'a, T>,
+    mut f: impl FnMut(T) -> U,
+) -> impl FnMut(&'a str) -> Parsed<'a, U> {
+    move |input: &'a str| parser.parse(input).map(|(value, rest)| (f(value), rest))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: String,
+    /// 1-based column, counted in characters, of the first character that did not match
+    pub column: usize,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Expected {} at column {}", self.expected, self.column)?;
+        writeln!(f, "{}", self.line)?;
+        write!(f, "{:>width$}", "^", width = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Runs the parser on a whole line, which has to be consumed completely
+pub fn parse_all<'a, T>(mut parser: impl Parser<'a, T>, line: &'a str) -> Result<T, ParseError> {
+    let error = |failure: Failure| ParseError {
+        line: line.to_string(),
+        column: line[..line.len() - failure.remaining].chars().count() + 1,
+        expected: failure.expected,
+    };
+
+    match parser.parse(line) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(error(Failure {
+            remaining: rest.len(),
+            expected: "the end of the line".to_string(),
+        })),
+        Err(failure) => Err(error(failure)),
+    }
+}
+
+/// Replaces Windows line endings and drops trailing whitespace, only allocating when the
+/// line endings need replacing
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim_end();
+    match trimmed.contains('\r') {
+        true => Cow::Owned(trimmed.replace("\r\n", "\n")),
+        false => Cow::Borrowed(trimmed),
+    }
 }
 
-impl ParseExt for &str {
-    fn iter_unsigned<'a, T>(
-        &'a self,
-    ) -> Box<dyn Iterator<Item = Result<T, <T as FromStr>::Err>> + 'a>
-    where
-        T: FromStr,
-    {
-        Box::new(self.split(&[' ', ',']).map(|c| c.parse::<T>()))
+/// Splits the input into the sections separated by blank lines. Lines holding only
+/// whitespace count as blank, and either line ending is accepted. The sections do not
+/// include their final line ending
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        sections.push(&input[start..end]);
+    }
+    sections
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected {} sections separated by blank lines, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for SectionError {}
+
+/// Splits the input into exactly `N` sections, see [`sections`]
+pub fn sections_n<const N: usize>(input: &str) -> Result<[&str; N], SectionError> {
+    let sections = sections(input);
+    let found = sections.len();
+
+    sections
+        .try_into()
+        .map_err(|_| SectionError { expected: N, found })
+}
+
+/// A tuple of functions, each parsing one section of the input
+pub trait SectionParsers<'a> {
+    type Output;
+    const COUNT: usize;
+
+    fn parse(self, sections: &[&'a str]) -> Self::Output;
+}
+
+/// Automatically generate section parsers for tuples of functions
+macro_rules! section_parsers {
+    ($count:literal: $($f:ident $t:ident $i:tt),*) => {
+        impl<'a, $($f, $t),*> SectionParsers<'a> for ($($f,)*)
+        where
+            $($f: FnOnce(&'a str) -> $t),*
+        {
+            type Output = ($($t,)*);
+            const COUNT: usize = $count;
+
+            fn parse(self, sections: &[&'a str]) -> Self::Output {
+                ($((self.$i)(sections[$i]),)*)
+            }
+        }
+    };
+}
+
+section_parsers!(1: F0 T0 0);
+section_parsers!(2: F0 T0 0, F1 T1 1);
+section_parsers!(3: F0 T0 0, F1 T1 1, F2 T2 2);
+section_parsers!(4: F0 T0 0, F1 T1 1, F2 T2 2, F3 T3 3);
+
+/// Splits the input into sections and parses each with the matching function, e.g.
+/// `parse_sections(raw, (workflows, parts))`. Fails if the number of sections differs from
+/// the number of functions
+pub fn parse_sections<'a, P: SectionParsers<'a>>(
+    input: &'a str,
+    parsers: P,
+) -> Result<P::Output, SectionError> {
+    let sections = sections(input);
+
+    match sections.len() == P::COUNT {
+        true => Ok(parsers.parse(&sections)),
+        false => Err(SectionError {
+            expected: P::COUNT,
+            found: sections.len(),
+        }),
     }
 }
 
-pub fn read(file_name: &str) -> String {
-    let mut f = File::open(file_name).expect(&format!(
-        "File not found: {}. Files are read relative to the Cargo.toml directory",
-        file_name
-    ));
+/// A file that could not be read, with the paths needed to tell why it was not found
+#[derive(Debug)]
+pub struct ReadError {
+    /// the absolute path the file was looked up at
+    pub path: PathBuf,
+    pub working_dir: Option<PathBuf>,
+    pub source: io::Error,
+}
+
+impl ReadError {
+    fn new(path: &Path, source: io::Error) -> Self {
+        ReadError {
+            path: std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+            working_dir: env::current_dir().ok(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cannot read {}: {}", self.path.display(), self.source)?;
+        match &self.working_dir {
+            Some(dir) => write!(f, " (working directory {})", dir.display()),
+            None => write!(f, " (unknown working directory)"),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
 
-    let mut contents = String::new();
+/// The workspace's puzzle input directory. It is looked up from the working directory and
+/// its parents first, so a moved binary still finds it, then from where this crate was built
+pub fn input_dir() -> PathBuf {
+    const INPUT: &str = "resources/input";
 
-    f.read_to_string(&mut contents)
-        .expect(&format!("cannot read file {}", file_name));
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .map(|dir| dir.join(INPUT))
+                .find(|dir| dir.is_dir())
+        })
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(INPUT))
+}
 
-    contents.to_string()
+/// The input file of a puzzle, e.g. `resources/input/year2023/day07.txt`
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    input_dir()
+        .join(format!("year{year}"))
+        .join(format!("day{day:02}.txt"))
 }
 
-pub fn read_to_vec(file_name: &str) -> Vec<String> {
-    let mut f = File::open(file_name).expect(&format!("file not found: {}", file_name));
+pub fn read(path: impl AsRef<Path>) -> Result<String, ReadError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| ReadError::new(path, e))
+}
 
-    let mut contents = String::new();
+/// Reads the lines of the file without trailing blank lines. The file is read through a
+/// buffer, so large inputs are never held in memory twice
+pub fn read_to_vec(path: impl AsRef<Path>) -> Result<Vec<String>, ReadError> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| ReadError::new(path, e))?;
 
-    f.read_to_string(&mut contents)
-        .expect(&format!("cannot read file {}", file_name));
+    let mut lines = BufReader::new(file)
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ReadError::new(path, e))?;
 
-    contents
-        .trim_end()
-        .split("\n")
-        .map(|s| s.to_owned())
-        .collect()
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if let Some(last) = lines.last_mut() {
+        last.truncate(last.trim_end().len());
+    }
+    Ok(lines)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn unsigned_test() {
+        let numbers = "x=787,m=2655;a=1222 s:2876".unsigned::<u32>();
+        assert_eq!(vec![787, 2655, 1222, 2876], numbers.collect::<Vec<_>>());
+
+        // signs are ignored
+        let numbers = "-1, 2 -> 3".unsigned::<u8>();
+        assert_eq!(vec![1, 2, 3], numbers.collect::<Vec<_>>());
+
+        assert_eq!(None, "no numbers".unsigned::<u8>().next());
+    }
+
+    #[test]
+    fn signed_test() {
+        let numbers = "p=-12,4 v=3,-0 - 5".signed::<i64>();
+        assert_eq!(vec![-12, 4, 3, 0, 5], numbers.collect::<Vec<_>>());
+
+        let numbers = "19, 13, 30 @ -2,  1, -2".signed::<i32>();
+        assert_eq!(vec![19, 13, 30, -2, 1, -2], numbers.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn collect_array_test() {
+        let [x, y, z] = "1,0,-3".signed::<i32>().collect_array().unwrap();
+        assert_eq!((1, 0, -3), (x, y, z));
+
+        assert_eq!(Some(["a", "b"]), "a b".split(' ').collect_array());
+        assert_eq!(None, "a b c".split(' ').collect_array::<2>());
+        assert_eq!(None, "a".split(' ').collect_array::<2>());
+    }
+
+    #[test]
+    fn number_bounds_test() {
+        assert_eq!(Some(255), "255".unsigned::<u8>().next());
+        assert_eq!(
+            Some(u64::MAX),
+            u64::MAX.to_string().unsigned::<u64>().next()
+        );
+        assert_eq!(
+            vec![-128, 127],
+            "-128 127".signed::<i8>().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(i64::MIN), i64::MIN.to_string().signed::<i64>().next());
+        assert_eq!(Some(i64::MAX), i64::MAX.to_string().signed::<i64>().next());
+    }
+
+    #[test]
+    #[should_panic(expected = "300 does not fit in u8")]
+    fn unsigned_overflow_test() {
+        "1 300".unsigned::<u8>().for_each(drop);
+    }
+
+    #[test]
+    #[should_panic(expected = "-129 does not fit in i8")]
+    fn signed_overflow_test() {
+        "-129".signed::<i8>().for_each(drop);
+    }
+
+    #[test]
+    #[should_panic(expected = "128 does not fit in i8")]
+    fn signed_positive_overflow_test() {
+        "-128 128".signed::<i8>().for_each(drop);
+    }
+
+    #[test]
+    fn combinator_test() {
+        let brick = pair(
+            terminated(sep_by(number::<i32>(), tag(",")), tag("~")),
+            sep_by(number::<i32>(), tag(",")),
+        );
+        assert_eq!(
+            Ok((vec![1, 0, -1], vec![1, 2, 1])),
+            parse_all(brick, "1,0,-1~1,2,1")
+        );
+
+        let module = pair(
+            pair(optional(one_of("%&")), word()),
+            preceded(tag(" -> "), sep_by(word(), tag(", "))),
+        );
+        assert_eq!(
+            Ok(((Some('%'), "a"), vec!["inv", "con"])),
+            parse_all(module, "%a -> inv, con")
+        );
+
+        let doubled = map(number::<u8>(), |n| n * 2);
+        assert_eq!(Ok(84), parse_all(doubled, "42"));
+        assert_eq!(
+            Ok(Vec::<&str>::new()),
+            parse_all(sep_by(word(), tag(",")), "")
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let arrow = pair(word(), preceded(tag(" -> "), word()));
+        let error = parse_all(arrow, "ab->c").unwrap_err();
+        assert_eq!(3, error.column);
+        assert_eq!(
+            "Expected \" -> \" at column 3\nab->c\n  ^",
+            error.to_string()
+        );
+
+        // columns count characters, not bytes
+        let arrow = pair(word(), preceded(tag(" -> "), word()));
+        let error = parse_all(arrow, "éa->c").unwrap_err();
+        assert_eq!(3, error.column);
+        assert_eq!(
+            "Expected \" -> \" at column 3\néa->c\n  ^",
+            error.to_string()
+        );
+
+        let error = parse_all(number::<u8>(), "12x").unwrap_err();
+        assert_eq!(3, error.column);
+        assert_eq!("the end of the line", error.expected);
+
+        let error = parse_all(number::<u8>(), "-").unwrap_err();
+        assert_eq!(1, error.column);
+
+        // a trailing separator needs another item
+        let error = parse_all(sep_by(word(), tag(", ")), "a, b, ").unwrap_err();
+        assert_eq!(7, error.column);
+        assert_eq!("a word", error.expected);
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!("a\nb\n\nc", normalize("a\r\nb\r\n\r\nc\r\n\r\n"));
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb")));
+    }
+
+    #[test]
+    fn sections_test() {
+        assert_eq!(vec!["a\nb", "c"], sections("a\nb\n\nc\n"));
+        assert_eq!(vec!["a\r\nb", "c"], sections("a\r\nb\r\n\r\nc\r\n"));
+        // blank lines may hold whitespace, and repeated blank lines do not add sections
+        assert_eq!(vec!["a", "b"], sections("\na\n  \n\n\t\nb"));
+        assert_eq!(Vec::<&str>::new(), sections("\n\n"));
+
+        assert_eq!(Ok(["a", "b"]), sections_n("a\n\nb\n"));
+        assert_eq!(
+            Err(SectionError {
+                expected: 3,
+                found: 2
+            }),
+            sections_n::<3>("a\n\nb\n")
+        );
+    }
+
+    #[test]
+    fn parse_sections_test() {
+        fn lines(section: &str) -> Vec<&str> {
+            section.lines().collect()
+        }
+
+        let input = "1,2,3\r\n\r\nx\r\ny\r\n";
+        let parsed = parse_sections(
+            input,
+            (|s: &str| s.unsigned::<u8>().collect::<Vec<_>>(), lines),
+        );
+        assert_eq!(Ok((vec![1, 2, 3], vec!["x", "y"])), parsed);
+
+        let error = parse_sections(input, (|s: &str| s.len(),)).unwrap_err();
+        assert_eq!(
+            "Expected 1 sections separated by blank lines, found 2",
+            error.to_string()
+        );
+    }
+
     #[test]
     fn read_test() {
         let relative_file = format!("../{}", file!());
-        assert!(read(&relative_file).contains(&"pub fn read(file_name: &str) -> String".to_owned()))
+        assert!(read(&relative_file).unwrap().contains(
+            &"pub fn read(path: impl AsRef<Path>) -> Result<String, ReadError>".to_owned()
+        ))
     }
 
     #[test]
     fn read_to_vec_test() {
         let relative_file = format!("../{}", file!());
         assert!(read_to_vec(&relative_file)
+            .unwrap()
             .iter()
-            .any(|l| l.contains(&"pub fn read(file_name: &str) -> String".to_owned())))
+            .any(|l| l.contains(
+                &"pub fn read(path: impl AsRef<Path>) -> Result<String, ReadError>".to_owned()
+            )))
+    }
+
+    #[test]
+    fn read_error_test() {
+        let error = read("missing/day00.txt").unwrap_err();
+        let working_dir = env::current_dir().unwrap();
+
+        assert_eq!(io::ErrorKind::NotFound, error.source.kind());
+        assert!(error.path.is_absolute());
+        assert!(error.path.ends_with("missing/day00.txt"));
+        assert_eq!(Some(&working_dir), error.working_dir.as_ref());
+
+        let message = error.to_string();
+        assert!(message.contains(&error.path.display().to_string()));
+        assert!(message.contains(&working_dir.display().to_string()));
+        assert!(read_to_vec("missing/day00.txt").is_err());
+    }
+
+    #[test]
+    fn input_dir_test() {
+        assert!(input_dir().ends_with("resources/input"));
+        assert!(input_dir().is_dir());
+        assert!(input_path(2023, 1).ends_with("year2023/day01.txt"));
     }
 }
@@ -1,5 +1,5 @@
 use core::fmt;
-use std::ops::{Add, AddAssign, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct Point {
@@ -48,14 +48,76 @@ impl AddAssign for Point {
     }
 }
 
-impl Mul<usize> for Point {
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Neg for Point {
     type Output = Self;
 
-    fn mul(self, rhs: usize) -> Self {
-        Point::new(self.x * rhs as i64, self.y * rhs as i64)
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
     }
 }
 
+/// Componentwise remainder, with the sign of the dividend (see [`Point::rem_euclid`])
+impl Rem for Point {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Point::new(self.x % rhs.x, self.y % rhs.y)
+    }
+}
+
+/// Automatically generate scalar operator traits for every integer type, so that
+/// both `point * 2` and `2 * point` work with any integer
+macro_rules! scalar {
+    ($($t:ty)*) => ($(
+        impl Mul<$t> for Point {
+            type Output = Self;
+
+            fn mul(self, rhs: $t) -> Self {
+                Point::new(self.x * rhs as i64, self.y * rhs as i64)
+            }
+        }
+
+        impl Mul<Point> for $t {
+            type Output = Point;
+
+            fn mul(self, rhs: Point) -> Point {
+                rhs * self
+            }
+        }
+
+        impl MulAssign<$t> for Point {
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Div<$t> for Point {
+            type Output = Self;
+
+            fn div(self, rhs: $t) -> Self {
+                Point::new(self.x / rhs as i64, self.y / rhs as i64)
+            }
+        }
+
+        impl Rem<$t> for Point {
+            type Output = Self;
+
+            fn rem(self, rhs: $t) -> Self {
+                Point::new(self.x % rhs as i64, self.y % rhs as i64)
+            }
+        }
+    )*)
+}
+
+scalar!(u8 u16 u32 usize i8 i16 i32 i64);
+
 impl Point {
     pub fn neighbours(self) -> [Point; 8] {
         [
@@ -80,6 +142,52 @@ impl Point {
     pub fn manhattan(self, other: Self) -> i64 {
         (self.x - other.x).abs() + (self.y - other.y).abs()
     }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Componentwise remainder which is never negative, mapping a point into the rectangle
+    /// between the origin and `modulus` (exclusive)
+    pub fn rem_euclid(self, modulus: Self) -> Self {
+        Point::new(self.x.rem_euclid(modulus.x), self.y.rem_euclid(modulus.y))
+    }
+
+    /// Componentwise division rounding towards negative infinity, the counterpart of
+    /// [`Point::rem_euclid`]
+    pub fn div_euclid(self, divisor: Self) -> Self {
+        Point::new(self.x.div_euclid(divisor.x), self.y.div_euclid(divisor.y))
+    }
+
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn abs(self) -> Self {
+        Point::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Componentwise minimum, unlike [`Ord::min`] which compares whole points
+    pub fn component_min(self, other: Self) -> Self {
+        Point::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Componentwise maximum, unlike [`Ord::max`] which compares whole points
+    pub fn component_max(self, other: Self) -> Self {
+        Point::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Rotates 90° clockwise (as displayed, with y pointing down) around `origin`
+    pub fn rotate_cw(self, origin: Self) -> Self {
+        let d = self - origin;
+        origin + Point::new(-d.y, d.x)
+    }
+
+    /// Rotates 90° counter-clockwise (as displayed, with y pointing down) around `origin`
+    pub fn rotate_ccw(self, origin: Self) -> Self {
+        let d = self - origin;
+        origin + Point::new(d.y, -d.x)
+    }
 }
 
 /// Automatically generate from tuple implementation traits
@@ -100,7 +208,7 @@ to_point!(u8 u16 u32 usize i8 i16 i32 i64);
 
 #[cfg(test)]
 mod test {
-    use crate::point::{Point, ORIGIN};
+    use crate::point::{Point, DOWN, LEFT, ORIGIN, RIGHT, UP};
 
     #[test]
     fn add_point_test() {
@@ -144,4 +252,75 @@ mod test {
             Point::new(-3, 4).cardinal()
         )
     }
+
+    #[test]
+    fn assign_point_test() {
+        let mut point = Point::new(1, 2);
+        point += Point::new(2, 2);
+        assert_eq!(Point::new(3, 4), point);
+        point -= Point::new(4, 4);
+        assert_eq!(Point::new(-1, 0), point);
+        point *= 3;
+        assert_eq!(Point::new(-3, 0), point);
+    }
+
+    #[test]
+    fn scalar_point_test() {
+        assert_eq!(Point::new(-1, 4), -Point::new(1, -4));
+        assert_eq!(Point::new(3, -6), Point::new(1, -2) * 3);
+        assert_eq!(Point::new(3, -6), 3 * Point::new(1, -2));
+        assert_eq!(Point::new(3, -6), Point::new(1, -2) * 3usize);
+        assert_eq!(Point::new(3, -6), 3i64 * Point::new(1, -2));
+        assert_eq!(Point::new(3, -3), Point::new(7, -7) / 2);
+        assert_eq!(Point::new(1, -1), Point::new(7, -7) % 2);
+        assert_eq!(Point::new(1, -1), Point::new(7, -7) % Point::new(3, 2));
+    }
+
+    #[test]
+    fn euclid_point_test() {
+        let size = Point::new(3, 4);
+        assert_eq!(Point::new(2, 1), Point::new(-1, 5).rem_euclid(size));
+        assert_eq!(Point::new(-1, 1), Point::new(-1, 5).div_euclid(size));
+
+        // quotient * divisor + remainder gives back the point
+        let point = Point::new(-7, 13);
+        let (q, r) = (point.div_euclid(size), point.rem_euclid(size));
+        assert_eq!(point, Point::new(q.x * size.x, q.y * size.y) + r);
+    }
+
+    #[test]
+    fn componentwise_point_test() {
+        assert_eq!(Point::new(1, -1), Point::new(5, -3).signum());
+        assert_eq!(Point::new(0, 0), ORIGIN.signum());
+        assert_eq!(Point::new(5, 3), Point::new(5, -3).abs());
+        assert_eq!(
+            Point::new(1, -3),
+            Point::new(5, -3).component_min(Point::new(1, 2))
+        );
+        assert_eq!(
+            Point::new(5, 2),
+            Point::new(5, -3).component_max(Point::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn rotate_point_test() {
+        assert_eq!(DOWN, RIGHT.rotate_cw(ORIGIN));
+        assert_eq!(RIGHT, UP.rotate_cw(ORIGIN));
+        assert_eq!(UP, RIGHT.rotate_ccw(ORIGIN));
+        assert_eq!(LEFT, UP.rotate_ccw(ORIGIN));
+
+        let centre = Point::new(2, 2);
+        assert_eq!(Point::new(2, 5), Point::new(5, 2).rotate_cw(centre));
+        assert_eq!(
+            Point::new(5, 2),
+            Point::new(5, 2).rotate_cw(centre).rotate_ccw(centre)
+        );
+    }
+
+    #[test]
+    fn distance_test() {
+        assert_eq!(7, Point::new(1, 2).manhattan(Point::new(-2, -2)));
+        assert_eq!(4, Point::new(1, 2).chebyshev(Point::new(-2, -2)));
+    }
 }
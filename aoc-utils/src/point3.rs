@@ -0,0 +1,217 @@
+use core::fmt;
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::pointn::{parse_coordinates, ParsePointError, PointN};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses `x,y,z`, ignoring whitespace around the coordinates
+impl FromStr for Point3 {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coordinates(s)?;
+        Ok(Point3::new(x, y, z))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Point3 {
+    /// The 6 points sharing a face with this one
+    pub fn cardinal(self) -> [Point3; 6] {
+        [
+            self + Point3::new(1, 0, 0),
+            self + Point3::new(-1, 0, 0),
+            self + Point3::new(0, 1, 0),
+            self + Point3::new(0, -1, 0),
+            self + Point3::new(0, 0, 1),
+            self + Point3::new(0, 0, -1),
+        ]
+    }
+
+    /// The 26 points surrounding this one, including the diagonals
+    pub fn neighbours(self) -> [Point3; 26] {
+        let mut neighbours = [self; 26];
+        let offsets = (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Point3::new(x, y, z))))
+            .filter(|&offset| offset != ORIGIN);
+
+        for (neighbour, offset) in neighbours.iter_mut().zip(offsets) {
+            *neighbour += offset;
+        }
+        neighbours
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn euclidean_squared(self, other: Self) -> i64 {
+        let d = self - other;
+        d.x * d.x + d.y * d.y + d.z * d.z
+    }
+}
+
+impl From<Point3> for PointN<3> {
+    fn from(point: Point3) -> Self {
+        PointN([point.x, point.y, point.z])
+    }
+}
+
+impl From<PointN<3>> for Point3 {
+    fn from(PointN([x, y, z]): PointN<3>) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+/// Automatically generate from tuple implementation traits
+macro_rules! to_point3 {
+    ($($t:ty)*) => ($(
+        impl From<($t, $t, $t)> for Point3 {
+            fn from((x, y, z): ($t, $t, $t)) -> Self {
+                Point3 {
+                    x: x as i64,
+                    y: y as i64,
+                    z: z as i64,
+                }
+            }
+        }
+    )*)
+}
+
+to_point3!(u8 u16 u32 usize i8 i16 i32 i64);
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{
+        point3::{Point3, ORIGIN},
+        pointn::PointN,
+    };
+
+    #[test]
+    fn operator_test() {
+        let a = Point3::new(1, 2, 3);
+
+        assert_eq!(Point3::new(2, 4, 6), a + a);
+        assert_eq!(ORIGIN, a - a);
+        assert_eq!(Point3::new(-1, -2, -3), -a);
+        assert_eq!(Point3::new(3, 6, 9), a * 3);
+        assert_eq!(a, Point3::from((1u8, 2u8, 3u8)));
+
+        let mut b = a;
+        b += a;
+        b -= Point3::new(0, 0, 1);
+        assert_eq!(Point3::new(2, 4, 5), b);
+    }
+
+    #[test]
+    fn distance_test() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(-1, 5, 3);
+
+        assert_eq!(5, a.manhattan(b));
+        assert_eq!(3, a.chebyshev(b));
+        assert_eq!(13, a.euclidean_squared(b));
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let point = Point3::new(1, 1, 1);
+
+        let cardinal = point.cardinal();
+        assert!(cardinal.iter().all(|p| p.manhattan(point) == 1));
+
+        let neighbours = point.neighbours();
+        assert_eq!(26, neighbours.iter().collect::<HashSet<_>>().len());
+        assert!(neighbours.iter().all(|p| p.chebyshev(point) == 1));
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Ok(Point3::new(1, -2, 30)), "1,-2,30".parse());
+        assert_eq!(Ok(Point3::new(1, 2, 3)), " 1, 2, 3 ".parse());
+        assert!("1,2".parse::<Point3>().is_err());
+        assert!("1,2,3,4".parse::<Point3>().is_err());
+
+        let point = Point3::new(4, 5, 6);
+        assert_eq!(point, Point3::from(PointN::from(point)));
+    }
+}
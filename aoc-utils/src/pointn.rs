@@ -0,0 +1,239 @@
+use core::fmt;
+use std::{
+    error::Error,
+    num::ParseIntError,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A point with any number of dimensions, for puzzles beyond two and three dimensions
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct PointN<const N: usize>(pub [i64; N]);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePointError {
+    Dimensions { expected: usize, found: usize },
+    Number(ParseIntError),
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePointError::Dimensions { expected, found } => {
+                write!(f, "Expected {expected} coordinates, found {found}")
+            }
+            ParsePointError::Number(e) => write!(f, "Invalid coordinate: {e}"),
+        }
+    }
+}
+
+impl Error for ParsePointError {}
+
+/// Parses `N` comma separated coordinates, e.g. `1,-2,3`, ignoring whitespace around them
+pub(crate) fn parse_coordinates<const N: usize>(s: &str) -> Result<[i64; N], ParsePointError> {
+    let mut coordinates = [0; N];
+    let mut found = 0;
+
+    for part in s.split(',') {
+        if found < N {
+            coordinates[found] = part.trim().parse().map_err(ParsePointError::Number)?;
+        }
+        found += 1;
+    }
+
+    match found == N {
+        true => Ok(coordinates),
+        false => Err(ParsePointError::Dimensions { expected: N, found }),
+    }
+}
+
+impl<const N: usize> PointN<N> {
+    pub const ORIGIN: Self = PointN([0; N]);
+
+    pub const fn new(coordinates: [i64; N]) -> Self {
+        PointN(coordinates)
+    }
+
+    fn zip(self, other: Self, f: impl Fn(i64, i64) -> i64) -> Self {
+        PointN(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self - other).0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self - other).0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    pub fn euclidean_squared(self, other: Self) -> i64 {
+        (self - other).0.iter().map(|c| c * c).sum()
+    }
+
+    /// The `2 * N` points one step away along a single axis
+    pub fn cardinal(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            [-1, 1].map(|step| {
+                let mut point = self;
+                point.0[axis] += step;
+                point
+            })
+        })
+    }
+
+    /// The `3^N - 1` points surrounding this one, including the diagonals
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        (0..3usize.pow(N as u32))
+            .map(move |mut index| {
+                let mut point = self;
+                for coordinate in point.0.iter_mut() {
+                    *coordinate += (index % 3) as i64 - 1;
+                    index /= 3;
+                }
+                point
+            })
+            .filter(move |&point| point != self)
+    }
+}
+
+impl<const N: usize> fmt::Display for PointN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, coordinate) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{coordinate}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<const N: usize> FromStr for PointN<N> {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_coordinates(s).map(PointN)
+    }
+}
+
+impl<const N: usize> Index<usize> for PointN<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for PointN<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for PointN<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> Sub for PointN<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> AddAssign for PointN<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for PointN<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> Neg for PointN<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        PointN(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i64> for PointN<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        PointN(self.0.map(|c| c * rhs))
+    }
+}
+
+/// Automatically generate from array implementation traits
+macro_rules! to_point_n {
+    ($($t:ty)*) => ($(
+        impl<const N: usize> From<[$t; N]> for PointN<N> {
+            fn from(coordinates: [$t; N]) -> Self {
+                PointN(coordinates.map(|c| c as i64))
+            }
+        }
+    )*)
+}
+
+to_point_n!(u8 u16 u32 usize i8 i16 i32 i64);
+
+#[cfg(test)]
+mod test {
+    use crate::pointn::{ParsePointError, PointN};
+
+    #[test]
+    fn operator_test() {
+        let a = PointN([1, 2, 3, 4]);
+        let b = PointN::from([4u8, 3, 2, 1]);
+
+        assert_eq!(PointN([5, 5, 5, 5]), a + b);
+        assert_eq!(PointN([-3, -1, 1, 3]), a - b);
+        assert_eq!(PointN([-1, -2, -3, -4]), -a);
+        assert_eq!(PointN([2, 4, 6, 8]), a * 2);
+        assert_eq!(3, a[2]);
+    }
+
+    #[test]
+    fn distance_test() {
+        let a = PointN([1, 2, 3, 4]);
+        let b = PointN([4, 3, 2, 1]);
+
+        assert_eq!(8, a.manhattan(b));
+        assert_eq!(3, a.chebyshev(b));
+        assert_eq!(20, a.euclidean_squared(b));
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let point = PointN([0, 0, 0, 0]);
+        assert_eq!(8, point.cardinal().count());
+        assert_eq!(80, point.neighbours().count());
+        assert!(point.neighbours().all(|p| p.chebyshev(point) == 1));
+        assert!(point.cardinal().all(|p| p.manhattan(point) == 1));
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Ok(PointN([1, -2, 3, 4])), "1,-2, 3,4".parse());
+        assert_eq!(
+            Err(ParsePointError::Dimensions {
+                expected: 4,
+                found: 3
+            }),
+            "1,2,3".parse::<PointN<4>>()
+        );
+        assert!("1,2,x,4".parse::<PointN<4>>().is_err());
+        assert_eq!("(1, 2)", PointN([1, 2]).to_string());
+    }
+}
@@ -0,0 +1,390 @@
+//! Shortest path searches over a graph given by a successor function, so that a puzzle only
+//! has to define its state and how to move from one state to the next.
+//!
+//! Costs only need to be ordered and summable, with [`Default`] as zero, which covers all
+//! integer types.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+const NO_PARENT: usize = usize::MAX;
+
+/// The result of a successful search
+#[derive(Debug, Clone)]
+pub struct Found<N, C> {
+    pub cost: C,
+    pub goal: N,
+    /// every node pushed during the search, along with the index of the node it was reached from
+    visited: Vec<(N, usize)>,
+    index: usize,
+}
+
+impl<N: Clone, C> Found<N, C> {
+    /// Reconstructs the path from the start to the goal (both inclusive)
+    pub fn path(&self) -> Vec<N> {
+        let mut path = vec![];
+        let mut index = self.index;
+
+        while index != NO_PARENT {
+            let (node, parent) = &self.visited[index];
+            path.push(node.clone());
+            index = *parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// An entry of the priority queue, referring to the node by its index in `visited`
+struct Queued<C> {
+    priority: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Queued<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the heap is a max-heap, so compare in reverse
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<C: Ord> PartialOrd for Queued<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Queued<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<C: Ord> Eq for Queued<C> {}
+
+/// Finds the cheapest path from any of the starts to a node for which `success` holds.
+/// `successors` returns the nodes reachable from a node along with the (non-negative) cost
+/// of moving there.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    a_star(starts, successors, |_| C::default(), success)
+}
+
+/// Same as [`dijkstra`], but explores the nodes closest to the goal first using `heuristic`,
+/// an estimate of the remaining cost which must never overestimate (e.g. [`Point::manhattan`]
+/// on a grid where every step costs at least 1).
+///
+/// [`Point::manhattan`]: crate::point::Point::manhattan
+pub fn a_star<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited: Vec<(N, usize)> = vec![];
+    let mut best: HashMap<N, C> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if best.insert(start.clone(), C::default()).is_none() {
+            queue.push(Queued {
+                priority: heuristic(&start),
+                cost: C::default(),
+                index: visited.len(),
+            });
+            visited.push((start, NO_PARENT));
+        }
+    }
+
+    while let Some(Queued { cost, index, .. }) = queue.pop() {
+        let node = &visited[index].0;
+
+        if best.get(node).is_some_and(|&b| b < cost) {
+            continue; // a cheaper way to this node was queued after this one
+        }
+
+        if success(node) {
+            let goal = node.clone();
+            return Some(Found {
+                cost,
+                goal,
+                visited,
+                index,
+            });
+        }
+
+        for (next, step) in successors(node) {
+            let next_cost = cost + step;
+
+            match best.entry(next.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(next_cost);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                }
+            }
+
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                index: visited.len(),
+            });
+            visited.push((next, index));
+        }
+    }
+    None
+}
+
+/// Finds the path with the fewest steps from any of the starts to a node for which
+/// `success` holds. The cost of the result is the number of steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited: Vec<(N, usize)> = vec![];
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back((0, visited.len()));
+            visited.push((start, NO_PARENT));
+        }
+    }
+
+    while let Some((steps, index)) = queue.pop_front() {
+        let node = &visited[index].0;
+
+        if success(node) {
+            let goal = node.clone();
+            return Some(Found {
+                cost: steps,
+                goal,
+                visited,
+                index,
+            });
+        }
+
+        for next in successors(node) {
+            if seen.insert(next.clone()) {
+                queue.push_back((steps + 1, visited.len()));
+                visited.push((next, index));
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from the nearest start to every node reachable from the starts
+pub fn bfs_distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    bfs_distances_within(starts, usize::MAX, successors)
+}
+
+/// Same as [`bfs_distances`], but stops exploring after `limit` steps, which keeps the search
+/// finite on infinite graphs
+pub fn bfs_distances_within<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    limit: usize,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if steps == limit {
+            continue;
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// The number of nodes on which a walk of exactly `steps` steps from any of the starts can end.
+///
+/// This assumes every cycle in the graph has an even length, as on a grid moving in the
+/// cardinal directions: a node reached in fewer steps of the same parity can then be reached
+/// in exactly `steps` by stepping back and forth.
+pub fn reachable_in_exactly<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    steps: usize,
+    successors: impl FnMut(&N) -> I,
+) -> usize
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    bfs_distances_within(starts, steps, successors)
+        .values()
+        .filter(|&distance| distance % 2 == steps % 2)
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grid::Grid,
+        point::Point,
+        search::{
+            a_star, bfs, bfs_distances, bfs_distances_within, dijkstra, reachable_in_exactly,
+        },
+    };
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+....#..
+.##...#
+...#...";
+
+    #[test]
+    fn bfs_test() {
+        let grid = Grid::parse_char(MAZE).unwrap();
+        let end = Point::new(6, 4);
+
+        let found = bfs(
+            [Point::new(0, 0)],
+            |&p| {
+                grid.neighbours(p)
+                    .filter(|&(_, c)| c == '.')
+                    .map(|(p, _)| p)
+            },
+            |&p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(10, found.cost);
+        let path = found.path();
+        assert_eq!(11, path.len());
+        assert_eq!(Point::new(0, 0), path[0]);
+        assert_eq!(end, path[10]);
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        assert!(bfs([Point::new(0, 0)], |_| [], |&p| p == end).is_none());
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let grid = Grid::parse("131\n191\n111").unwrap();
+        let end = Point::new(2, 2);
+        let successors = |&p: &Point| grid.neighbours(p);
+
+        let found = dijkstra([Point::new(0, 0)], successors, |&p| p == end).unwrap();
+        assert_eq!(4, found.cost);
+        assert_eq!(
+            vec![
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(1, 2),
+                Point::new(2, 2)
+            ],
+            found.path()
+        );
+
+        let heuristic = |&p: &Point| p.manhattan(end);
+        let found = a_star([Point::new(0, 0)], successors, heuristic, |&p| p == end).unwrap();
+        assert_eq!(4, found.cost);
+        assert_eq!(end, found.goal);
+    }
+
+    #[test]
+    fn multiple_starts_test() {
+        // every step costs 1, so the start closest to the goal wins
+        let found = dijkstra(
+            [10, 3],
+            |&n: &i64| {
+                [(n - 1, 1), (n + 1, 1)]
+                    .into_iter()
+                    .filter(|&(n, _)| n >= 0)
+            },
+            |&n| n == 0,
+        )
+        .unwrap();
+
+        assert_eq!(3, found.cost);
+        assert_eq!(vec![3, 2, 1, 0], found.path());
+    }
+
+    #[test]
+    fn bfs_distances_test() {
+        let grid = Grid::parse_char(MAZE).unwrap();
+        let open = |&p: &Point| {
+            grid.neighbours(p)
+                .filter(|&(_, c)| c == '.')
+                .map(|(p, _)| p)
+        };
+
+        let distances = bfs_distances([Point::new(0, 0)], open);
+        assert_eq!(Some(&0), distances.get(&Point::new(0, 0)));
+        assert_eq!(Some(&10), distances.get(&Point::new(6, 4)));
+        assert_eq!(None, distances.get(&Point::new(2, 0)));
+        assert_eq!(grid.find_all('.').count(), distances.len());
+
+        let within = bfs_distances_within([Point::new(0, 0)], 2, open);
+        assert_eq!(4, within.len());
+
+        // the nearest start wins
+        let multi = bfs_distances([Point::new(0, 0), Point::new(6, 4)], open);
+        assert_eq!(Some(&0), multi.get(&Point::new(6, 4)));
+        assert_eq!(Some(&1), multi.get(&Point::new(5, 4)));
+    }
+
+    #[test]
+    fn reachable_in_exactly_test() {
+        // an open plane, where the reachable points form a diamond of every other point
+        let plane = |p: &Point| p.cardinal();
+
+        assert_eq!(1, reachable_in_exactly([Point::new(0, 0)], 0, plane));
+        assert_eq!(4, reachable_in_exactly([Point::new(0, 0)], 1, plane));
+        assert_eq!(9, reachable_in_exactly([Point::new(0, 0)], 2, plane));
+        assert_eq!(16, reachable_in_exactly([Point::new(0, 0)], 3, plane));
+    }
+}
@@ -0,0 +1,277 @@
+use std::{collections::HashMap, fmt, ops::Index};
+
+use crate::{grid::Grid, neighbourhood::Neighbourhood, point::Point};
+
+/// A grid without fixed bounds, only storing the points that have a value. The bounding box
+/// grows with every point inserted, so it can be converted to a dense [`Grid`] or displayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    data: HashMap<Point, T>,
+    /// top-left and bottom-right corners (inclusive) of the points with a value
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            data: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.component_min(point), max.component_max(point)),
+            None => (point, point),
+        });
+        self.data.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let removed = self.data.remove(&point);
+
+        // the bounding box can only shrink if the point was on its edge
+        if let Some((min, max)) = self.bounds {
+            if removed.is_some()
+                && (point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y)
+            {
+                self.bounds = Self::compute_bounds(self.data.keys());
+            }
+        }
+        removed
+    }
+
+    fn compute_bounds<'a>(points: impl Iterator<Item = &'a Point>) -> Option<(Point, Point)> {
+        points.fold(None, |bounds, &p| match bounds {
+            Some((min, max)) => Some((min.component_min(p), max.component_max(p))),
+            None => Some((p, p)),
+        })
+    }
+
+    /// The top-left and bottom-right corners (inclusive) of the bounding box
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> i64 {
+        self.bounds.map_or(0, |(min, max)| max.x - min.x + 1)
+    }
+
+    pub fn height(&self) -> i64 {
+        self.bounds.map_or(0, |(min, max)| max.y - min.y + 1)
+    }
+
+    /// Whether the point has a value
+    pub fn contains(&self, point: Point) -> bool {
+        self.data.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.data.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.data.get_mut(&point)
+    }
+
+    /// Every point with a value, in no particular order
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.data.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Copies the grid into a dense grid covering the bounding box, filling the points without
+    /// a value with `empty`. The top-left corner of the bounding box becomes the origin
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, _)) = self.bounds else {
+            return Grid::new(0, 0, empty);
+        };
+
+        Grid::from_fn(self.width(), self.height(), |point| {
+            self.get(point + min).unwrap_or(&empty).clone()
+        })
+    }
+
+    /// Copies the cells of a dense grid for which `keep` holds
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.iter_points()
+            .filter(|(_, value)| keep(value))
+            .map(|(point, value)| (point, value.clone()))
+            .collect()
+    }
+}
+
+impl<T: Copy + PartialEq> SparseGrid<T> {
+    /// The cardinal neighbours with a value
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, T)> + '_ {
+        self.neighbours_with(point, Neighbourhood::Cardinal)
+    }
+
+    /// The neighbours with a value, for any neighbourhood
+    pub fn neighbours_with<'n>(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood<'n>,
+    ) -> impl Iterator<Item = (Point, T)> + use<'_, 'n, T> {
+        neighbourhood.offsets().iter().filter_map(move |&offset| {
+            self.get(point + offset)
+                .map(|&value| (point + offset, value))
+        })
+    }
+
+    /**
+     * Returns the coordinate of the first match, row by row
+     */
+    pub fn find(&self, needle: T) -> Option<Point> {
+        self.find_all(needle).min_by_key(|p| (p.y, p.x))
+    }
+
+    /**
+     * Returns the coordinates of all matches, in no particular order
+     */
+    pub fn find_all(&self, needle: T) -> impl Iterator<Item = Point> + '_ {
+        self.data
+            .iter()
+            .filter(move |(_, &value)| value == needle)
+            .map(|(&point, _)| point)
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    // points without a value will panic
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {point} has no value"))
+    }
+}
+
+/// Displays the bounding box, with `.` for the points without a value
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{grid::Grid, neighbourhood::Neighbourhood, point::Point, sparse::SparseGrid};
+
+    #[test]
+    fn bounds_test() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+
+        grid.insert(Point::new(2, 3), '#');
+        grid.insert(Point::new(-4, 1), '#');
+        grid.insert(Point::new(0, 7), '#');
+
+        assert_eq!(Some((Point::new(-4, 1), Point::new(2, 7))), grid.bounds());
+        assert_eq!(7, grid.width());
+        assert_eq!(7, grid.height());
+
+        grid.remove(Point::new(-4, 1));
+        assert_eq!(Some((Point::new(0, 3), Point::new(2, 7))), grid.bounds());
+        assert_eq!(2, grid.len());
+    }
+
+    #[test]
+    fn neighbours_find_test() {
+        let grid: SparseGrid<char> = [
+            (Point::new(0, 0), 'a'),
+            (Point::new(1, 0), 'b'),
+            (Point::new(0, -1), 'b'),
+            (Point::new(5, 5), 'c'),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut neighbours = grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>();
+        neighbours.sort();
+        assert_eq!(
+            vec![(Point::new(0, -1), 'b'), (Point::new(1, 0), 'b')],
+            neighbours
+        );
+
+        assert_eq!(Some(Point::new(0, -1)), grid.find('b'));
+        assert_eq!(2, grid.find_all('b').count());
+        assert_eq!(None, grid.find('d'));
+        assert_eq!(
+            3,
+            grid.neighbours_with(Point::new(1, -1), Neighbourhood::Moore)
+                .count()
+        );
+        assert_eq!('c', grid[Point::new(5, 5)]);
+    }
+
+    #[test]
+    fn display_test() {
+        let grid: SparseGrid<char> = [(Point::new(-1, -1), '#'), (Point::new(1, 0), '#')]
+            .into_iter()
+            .collect();
+
+        assert_eq!("#..\n..#", grid.to_string());
+        assert_eq!("", SparseGrid::<char>::new().to_string());
+    }
+
+    #[test]
+    fn conversion_test() {
+        let dense = Grid::parse_char("#..\n.#.\n..#").unwrap();
+        let sparse = SparseGrid::from_grid(&dense, |&c| c == '#');
+
+        assert_eq!(3, sparse.len());
+        assert!(sparse.contains(Point::new(1, 1)));
+        assert!(!sparse.contains(Point::new(1, 0)));
+        assert_eq!(dense.data, sparse.to_grid('.').data);
+
+        let shifted: SparseGrid<char> = [(Point::new(10, 10), '#'), (Point::new(11, 11), '#')]
+            .into_iter()
+            .collect();
+        assert_eq!("#.\n.#", shifted.to_grid('.').to_string());
+    }
+}
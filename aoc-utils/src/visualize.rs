@@ -0,0 +1,208 @@
+//! Terminal animations of successive frames, e.g. the states of a simulation:
+//!
+//! ```ignore
+//! let mut animation = Animation::new().fps(10).frames(100);
+//! while animation.show(grid.render().highlight(reached.iter().copied(), GREEN)) {
+//!     reached = step(&reached);
+//! }
+//! ```
+
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{stdin, stdout, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    thread::sleep,
+    time::Duration,
+};
+
+use crate::ansi::{BOLD, CLEAR, HOME, RESET};
+
+pub struct Animation {
+    out: Box<dyn Write>,
+    input: Box<dyn BufRead>,
+    delay: Duration,
+    /// wait for the user before showing the next frame
+    step: bool,
+    max_frames: Option<usize>,
+    frame: usize,
+    /// frames are written one after another instead of redrawn in place
+    dump: bool,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Animation {
+    /// An animation redrawing the terminal at 30 frames per second
+    pub fn new() -> Self {
+        Animation {
+            out: Box::new(stdout()),
+            input: Box::new(BufReader::new(stdin())),
+            delay: Duration::from_secs(1) / 30,
+            step: false,
+            max_frames: None,
+            frame: 0,
+            dump: false,
+        }
+    }
+
+    /// Writes the frames to a text file instead of the terminal, without any delay
+    pub fn to_file(path: &Path) -> std::io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::with_writer(BufWriter::new(file)))
+    }
+
+    /// Writes the frames one after another to `out`, without any delay
+    pub fn with_writer(out: impl Write + 'static) -> Self {
+        Animation {
+            out: Box::new(out),
+            delay: Duration::ZERO,
+            dump: true,
+            ..Self::new()
+        }
+    }
+
+    pub fn fps(mut self, fps: u32) -> Self {
+        self.delay = Duration::from_secs(1) / fps.max(1);
+        self
+    }
+
+    /// Stops the animation after a number of frames
+    pub fn frames(mut self, frames: usize) -> Self {
+        self.max_frames = Some(frames);
+        self
+    }
+
+    /// Waits for a keypress after every frame. As the terminal is line buffered the key has to
+    /// be followed by enter: enter steps to the next frame, `c` continues playing and `q` quits
+    pub fn step(mut self) -> Self {
+        self.step = true;
+        self
+    }
+
+    /// Reads the keypresses for [`Animation::step`] from `input` instead of stdin
+    pub fn with_input(mut self, input: impl BufRead + 'static) -> Self {
+        self.input = Box::new(input);
+        self
+    }
+
+    /// The number of frames shown so far
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Shows the next frame. Returns `false` once the animation should stop, either because
+    /// the frame limit was reached or the user quit
+    pub fn show(&mut self, frame: impl Display) -> bool {
+        if self.max_frames.is_some_and(|max| self.frame >= max) {
+            return false;
+        }
+        self.frame += 1;
+
+        let written = match self.dump {
+            true => writeln!(self.out, "--- frame {} ---\n{frame}", self.frame),
+            false => writeln!(
+                self.out,
+                "{HOME}{CLEAR}{frame}\n{BOLD}frame {}{RESET}",
+                self.frame
+            ),
+        };
+
+        if written.and_then(|_| self.out.flush()).is_err() {
+            return false;
+        }
+
+        if self.step {
+            let mut key = String::new();
+            match self.input.read_line(&mut key) {
+                Ok(0) | Err(_) => return false, // no more input
+                _ => match key.trim() {
+                    "q" => return false,
+                    "c" => self.step = false,
+                    _ => {}
+                },
+            }
+        } else if !self.delay.is_zero() {
+            sleep(self.delay);
+        }
+
+        self.max_frames.is_none_or(|max| self.frame < max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Cursor, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use crate::visualize::Animation;
+
+    /// A writer the test can read back after handing it to the animation
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Shared {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn dump_test() {
+        let out = Shared::default();
+        let mut animation = Animation::with_writer(out.clone());
+
+        assert!(animation.show("ab\ncd"));
+        assert!(animation.show(1));
+
+        assert_eq!("--- frame 1 ---\nab\ncd\n--- frame 2 ---\n1\n", out.text());
+    }
+
+    #[test]
+    fn frames_test() {
+        let out = Shared::default();
+        let mut animation = Animation::with_writer(out.clone()).frames(2);
+
+        assert!(animation.show("a"));
+        assert!(!animation.show("b"));
+        assert!(!animation.show("c"));
+
+        assert_eq!(2, animation.frame());
+        assert!(!out.text().contains('c'));
+    }
+
+    #[test]
+    fn step_test() {
+        let input = Cursor::new("\nq\n");
+        let mut animation = Animation::with_writer(Shared::default())
+            .step()
+            .with_input(input);
+
+        assert!(animation.show("a"));
+        assert!(!animation.show("b"));
+
+        let input = Cursor::new("c\n");
+        let mut animation = Animation::with_writer(Shared::default())
+            .step()
+            .with_input(input);
+
+        assert!(animation.show("a"));
+        assert!(animation.show("b")); // playing, so no more input is needed
+    }
+}